@@ -1,29 +1,56 @@
+use crate::sasm::{Trap, TrapKind};
+
 /// The different actions that can occur in the VM.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Action {
     /// Stops the VM allowing to resume its execution.
     Halt,
 
     /// Stops the VM because of an error.
-    Panic(&'static str),
+    Trap(Trap),
+
+    /// Stops the VM because the end of the code has been reached.
+    End,
+
+    /// Stops the VM because the fuel left cannot pay the next instruction. The execution
+    /// can be resumed after adding more fuel.
+    OutOfFuel,
 }
 
 impl Action {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a trap of `kind`. Its program counter is set by the processor.
+    pub fn trap(kind: TrapKind) -> Action {
+        Action::Trap(Trap {
+            program_counter: 0,
+            kind,
+        })
+    }
+
     // GETTERS ----------------------------------------------------------------
 
     pub fn is_halt(&self) -> bool {
         matches!(self, Action::Halt)
     }
 
-    pub fn is_panic(&self) -> bool {
-        matches!(self, Action::Panic(_))
+    pub fn is_trap(&self) -> bool {
+        matches!(self, Action::Trap(_))
+    }
+
+    pub fn is_end(&self) -> bool {
+        matches!(self, Action::End)
+    }
+
+    pub fn is_out_of_fuel(&self) -> bool {
+        matches!(self, Action::OutOfFuel)
     }
 
     // METHODS ----------------------------------------------------------------
 
-    pub fn unwrap_panic(self) -> &'static str {
+    pub fn unwrap_trap(self) -> Trap {
         match self {
-            Action::Panic(v) => v,
+            Action::Trap(v) => v,
             _ => unreachable!(),
         }
     }
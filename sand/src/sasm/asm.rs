@@ -0,0 +1,711 @@
+use std::collections::HashMap;
+
+use crate::sasm::instructions::Instruction;
+use crate::sasm::Program;
+
+/// An error found by the assembler in the source of a program.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AssemblerError {
+    /// The line of the source where the error is, starting at 1.
+    pub line: usize,
+    pub kind: AssemblerErrorKind,
+}
+
+/// The kind of assembler error.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AssemblerErrorKind {
+    /// The mnemonic does not match any instruction.
+    UnknownInstruction(String),
+
+    /// The directive does not exist.
+    UnknownDirective(String),
+
+    /// The label is defined more than once.
+    DuplicatedLabel(String),
+
+    /// The label is used but never defined.
+    UndefinedLabel(String),
+
+    /// The operand is neither a number nor a label, or a label is used where
+    /// only numbers are allowed.
+    InvalidOperand(String),
+
+    /// The number does not fit in the size of the operand.
+    NumberOutOfRange(String),
+
+    /// The instruction or directive receives a wrong number of operands.
+    WrongOperandCount,
+
+    /// The instruction is placed in the data section.
+    InstructionInDataSection,
+
+    /// The string is not closed or contains an invalid escape sequence.
+    InvalidString,
+}
+
+/// Assembles a textual listing into a program.
+///
+/// The syntax is line based:
+/// - `; text` - A comment until the end of the line.
+/// - `name:` - Defines a label at the current position. It can precede a directive or
+///   an instruction in the same line.
+/// - `.data` and `.code` - Select the section where the following lines are placed.
+///   The code section is selected by default.
+/// - `.byte`, `.u16`, `.u32` and `.u64` - Place their operands as numbers of 8, 16, 32
+///   or 64 bits. `.u32` also accepts labels.
+/// - `.ascii "text"` - Places the bytes of the strings. Supports the escape sequences
+///   `\n`, `\r`, `\t`, `\0`, `\\`, `\"` and `\xNN`.
+/// - `mnemonic operand` - Places an instruction, optionally followed by its immediate
+///   value. The mnemonics are the names of the instruction methods, e.g. `const_32`.
+/// - `branch_table entry, ..., default` - Places a branch table with the given entries
+///   followed by the default one.
+///
+/// Numbers can be written in decimal, in hexadecimal with the `0x` prefix or as negative
+/// decimals, which are stored in two's complement. Labels are resolved to their u32 position
+/// in the program, i.e. the positions that `branch` and `program_data_load_*` expect, except
+/// in relative branches like `branch_rel`, where they are resolved to the offset from the end
+/// of the instruction.
+pub fn assemble(source: &str) -> Result<Program, AssemblerError> {
+    let mut assembler = Assembler::default();
+
+    for (index, line) in source.lines().enumerate() {
+        assembler
+            .assemble_line(line)
+            .map_err(|kind| AssemblerError {
+                line: index + 1,
+                kind,
+            })?;
+    }
+
+    assembler.finish()
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Section {
+    Data,
+    Code,
+}
+
+/// A placeholder for a label position that is written once all labels are known.
+struct Fixup {
+    line: usize,
+    section: Section,
+    position: usize,
+    label: String,
+    /// The code position the label is written relative to, if any.
+    relative_to: Option<usize>,
+}
+
+enum Token<'a> {
+    Word(&'a str),
+    String(Vec<u8>),
+}
+
+struct Assembler {
+    line: usize,
+    section: Section,
+    data: Vec<u8>,
+    code: Vec<u8>,
+    labels: HashMap<String, (Section, usize)>,
+    fixups: Vec<Fixup>,
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Assembler {
+            line: 0,
+            section: Section::Code,
+            data: Vec::new(),
+            code: Vec::new(),
+            labels: HashMap::new(),
+            fixups: Vec::new(),
+        }
+    }
+}
+
+impl Assembler {
+    // METHODS ----------------------------------------------------------------
+
+    fn assemble_line(&mut self, line: &str) -> Result<(), AssemblerErrorKind> {
+        self.line += 1;
+
+        let tokens = tokenize(line)?;
+        let mut tokens = &tokens[..];
+
+        while let Some(Token::Word(word)) = tokens.first() {
+            let label = match word.strip_suffix(':') {
+                Some(v) => v,
+                None => break,
+            };
+
+            if !is_label(label) {
+                return Err(AssemblerErrorKind::InvalidOperand(word.to_string()));
+            }
+
+            let position = self.bytes().len();
+            if self
+                .labels
+                .insert(label.to_string(), (self.section, position))
+                .is_some()
+            {
+                return Err(AssemblerErrorKind::DuplicatedLabel(label.to_string()));
+            }
+
+            tokens = &tokens[1..];
+        }
+
+        let (name, operands) = match tokens.split_first() {
+            Some((Token::Word(name), operands)) => (*name, operands),
+            Some((Token::String(_), _)) => return Err(AssemblerErrorKind::InvalidString),
+            None => return Ok(()),
+        };
+
+        match name {
+            ".data" | ".code" => {
+                if !operands.is_empty() {
+                    return Err(AssemblerErrorKind::WrongOperandCount);
+                }
+
+                self.section = if name == ".data" {
+                    Section::Data
+                } else {
+                    Section::Code
+                };
+            }
+            ".byte" => self.assemble_numbers(operands, 1, None)?,
+            ".u16" => self.assemble_numbers(operands, 2, None)?,
+            ".u32" => self.assemble_numbers(operands, 4, None)?,
+            ".u64" => self.assemble_numbers(operands, 8, None)?,
+            ".ascii" => {
+                if operands.is_empty() {
+                    return Err(AssemblerErrorKind::WrongOperandCount);
+                }
+
+                for operand in operands {
+                    match operand {
+                        Token::String(v) => self.bytes_mut().extend_from_slice(v),
+                        Token::Word(v) => {
+                            return Err(AssemblerErrorKind::InvalidOperand(v.to_string()))
+                        }
+                    }
+                }
+            }
+            _ if name.starts_with('.') => {
+                return Err(AssemblerErrorKind::UnknownDirective(name.to_string()))
+            }
+            _ => self.assemble_instruction(name, operands)?,
+        }
+
+        Ok(())
+    }
+
+    fn assemble_instruction(
+        &mut self,
+        name: &str,
+        operands: &[Token],
+    ) -> Result<(), AssemblerErrorKind> {
+        let instruction = Instruction::from_name(name)
+            .ok_or_else(|| AssemblerErrorKind::UnknownInstruction(name.to_string()))?;
+
+        if self.section != Section::Code {
+            return Err(AssemblerErrorKind::InstructionInDataSection);
+        }
+
+        if instruction == Instruction::BranchTable {
+            return self.assemble_branch_table(operands);
+        }
+
+        let immediate_size = instruction.immediate_size();
+        let expected_operands = if immediate_size == 0 { 0 } else { 1 };
+        if operands.len() != expected_operands {
+            return Err(AssemblerErrorKind::WrongOperandCount);
+        }
+
+        instruction.write_opcode(&mut self.code);
+
+        let relative_to = if instruction.is_relative_branch() {
+            Some(self.code.len() + immediate_size)
+        } else {
+            None
+        };
+        self.assemble_numbers(operands, immediate_size, relative_to)
+    }
+
+    /// Places a `branch_table` whose operands are the entries of the table followed by
+    /// the default entry.
+    fn assemble_branch_table(&mut self, operands: &[Token]) -> Result<(), AssemblerErrorKind> {
+        if operands.is_empty() {
+            return Err(AssemblerErrorKind::WrongOperandCount);
+        }
+
+        let count = operands.len() - 1;
+        Instruction::BranchTable.write_opcode(&mut self.code);
+        self.code.extend_from_slice(&(count as u32).to_le_bytes());
+
+        let entry_size = std::mem::size_of::<i32>();
+        let end_position = self.code.len() + operands.len() * entry_size;
+        self.assemble_numbers(operands, entry_size, Some(end_position))
+    }
+
+    fn assemble_numbers(
+        &mut self,
+        operands: &[Token],
+        size: usize,
+        relative_to: Option<usize>,
+    ) -> Result<(), AssemblerErrorKind> {
+        if operands.is_empty() && size != 0 {
+            return Err(AssemblerErrorKind::WrongOperandCount);
+        }
+
+        for operand in operands {
+            let word = match operand {
+                Token::Word(v) => *v,
+                Token::String(_) => return Err(AssemblerErrorKind::InvalidString),
+            };
+
+            let value = if is_label(word) {
+                if size != std::mem::size_of::<u32>() {
+                    return Err(AssemblerErrorKind::InvalidOperand(word.to_string()));
+                }
+
+                self.fixups.push(Fixup {
+                    line: self.line,
+                    section: self.section,
+                    position: self.bytes().len(),
+                    label: word.to_string(),
+                    relative_to,
+                });
+
+                0
+            } else {
+                parse_number(word, size)?
+            };
+
+            let bytes = value.to_le_bytes();
+            self.bytes_mut().extend_from_slice(&bytes[..size]);
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Program, AssemblerError> {
+        let data_size = self.data.len();
+
+        for fixup in &self.fixups {
+            let position = match self.labels.get(&fixup.label) {
+                Some((Section::Data, position)) => *position,
+                Some((Section::Code, position)) => data_size + *position,
+                None => {
+                    return Err(AssemblerError {
+                        line: fixup.line,
+                        kind: AssemblerErrorKind::UndefinedLabel(fixup.label.clone()),
+                    })
+                }
+            };
+
+            let bytes = match fixup.section {
+                Section::Data => &mut self.data,
+                Section::Code => &mut self.code,
+            };
+            let value = match fixup.relative_to {
+                Some(v) => position.wrapping_sub(data_size + v) as u32,
+                None => position as u32,
+            };
+            bytes[fixup.position..fixup.position + std::mem::size_of::<u32>()]
+                .copy_from_slice(&value.to_le_bytes());
+        }
+
+        Ok(Program::from_sections(&self.data, &self.code))
+    }
+
+    fn bytes(&self) -> &Vec<u8> {
+        match self.section {
+            Section::Data => &self.data,
+            Section::Code => &self.code,
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut Vec<u8> {
+        match self.section {
+            Section::Data => &mut self.data,
+            Section::Code => &mut self.code,
+        }
+    }
+}
+
+/// Splits a line into words and strings, ignoring commas and comments.
+fn tokenize(line: &str) -> Result<Vec<Token<'_>>, AssemblerErrorKind> {
+    let mut tokens = Vec::new();
+    let mut rest = line;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+        if rest.is_empty() || rest.starts_with(';') {
+            return Ok(tokens);
+        }
+
+        if let Some(string) = rest.strip_prefix('"') {
+            let (bytes, length) = parse_string(string)?;
+            tokens.push(Token::String(bytes));
+            rest = &string[length..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == ',' || c == ';' || c == '"')
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+}
+
+/// Parses the content of a string after its opening quote and returns its bytes
+/// and the length consumed including the closing quote.
+fn parse_string(text: &str) -> Result<(Vec<u8>, usize), AssemblerErrorKind> {
+    let mut bytes = Vec::new();
+    let mut chars = text.char_indices();
+
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '"' => return Ok((bytes, index + 1)),
+            '\\' => {
+                let byte = match chars.next() {
+                    Some((_, 'n')) => b'\n',
+                    Some((_, 'r')) => b'\r',
+                    Some((_, 't')) => b'\t',
+                    Some((_, '0')) => b'\0',
+                    Some((_, '\\')) => b'\\',
+                    Some((_, '"')) => b'"',
+                    Some((index, 'x')) => {
+                        let digits = text
+                            .get(index + 1..index + 3)
+                            .ok_or(AssemblerErrorKind::InvalidString)?;
+                        chars.next();
+                        chars.next();
+                        u8::from_str_radix(digits, 16)
+                            .map_err(|_| AssemblerErrorKind::InvalidString)?
+                    }
+                    _ => return Err(AssemblerErrorKind::InvalidString),
+                };
+
+                bytes.push(byte);
+            }
+            _ => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+
+    Err(AssemblerErrorKind::InvalidString)
+}
+
+/// Parses a number that must fit in `size` bytes.
+fn parse_number(text: &str, size: usize) -> Result<u64, AssemblerErrorKind> {
+    let (is_negative, digits) = match text.strip_prefix('-') {
+        Some(v) => (true, v),
+        None => (false, text),
+    };
+
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(v) => u64::from_str_radix(v, 16),
+        None => digits.parse::<u64>(),
+    }
+    .map_err(|_| AssemblerErrorKind::InvalidOperand(text.to_string()))?;
+
+    let bits = 8 * size as u32;
+    let max_value = u64::MAX >> (64 - bits);
+    if is_negative {
+        let min_value = 1u64 << (bits - 1);
+        if magnitude > min_value {
+            return Err(AssemblerErrorKind::NumberOutOfRange(text.to_string()));
+        }
+
+        Ok(magnitude.wrapping_neg() & max_value)
+    } else {
+        if magnitude > max_value {
+            return Err(AssemblerErrorKind::NumberOutOfRange(text.to_string()));
+        }
+
+        Ok(magnitude)
+    }
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(v) if v.is_ascii_alphabetic() || v == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|v| v.is_ascii_alphanumeric() || v == '_' || v == '.')
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::disasm::disassemble_instruction;
+    use crate::sasm::instructions::EXTENDED_OPCODE;
+    use crate::sasm::{verify, Processor};
+
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        let program = assemble(
+            "; Loads a value from the data.
+            .data
+            value: .u16 0x1234
+            .code
+                const_32 value
+                program_data_load_16
+                const_32 end   ; Skips the unreachable.
+                branch
+                unreachable
+            end:
+                const_8 -1
+            .data
+                .ascii \"a;\\x62\"
+            ",
+        )
+        .expect("The assembly must succeed");
+
+        assert_eq!(
+            program.data(),
+            &[0x34, 0x12, b'a', b';', b'b'],
+            "The data is incorrect"
+        );
+        assert_eq!(
+            program.code(),
+            &[
+                Instruction::Const32 as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::ProgramDataLoad16 as u8,
+                Instruction::Const32 as u8,
+                0x12,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Branch as u8,
+                Instruction::Unreachable as u8,
+                Instruction::Const8 as u8,
+                0xff,
+            ],
+            "The code is incorrect"
+        );
+
+        let mut processor = Processor::new_empty(program, 20);
+        let result = processor.run();
+        assert!(result.is_end(), "The program must reach the end");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0xff,
+            "The first value of the stack is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x1234,
+            "The second value of the stack is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_assemble_relative() {
+        let program = assemble(
+            "start:
+                const_8 1
+                branch_rel_if_8 end
+                branch_rel start
+            end:
+                nop",
+        )
+        .expect("The assembly must succeed");
+
+        let branch_rel_if = (Instruction::BranchRelIf8 as usize - 256) as u8;
+        let branch_rel = (Instruction::BranchRel as usize - 256) as u8;
+        assert_eq!(
+            program.code(),
+            &[
+                Instruction::Const8 as u8,
+                0x01,
+                EXTENDED_OPCODE,
+                branch_rel_if,
+                0x06,
+                0x00,
+                0x00,
+                0x00,
+                EXTENDED_OPCODE,
+                branch_rel,
+                0xf2,
+                0xff,
+                0xff,
+                0xff,
+                Instruction::Nop as u8,
+            ],
+            "The code is incorrect"
+        );
+
+        verify(&program).expect("The verification must succeed");
+
+        let mut processor = Processor::new_empty(program, 20);
+        let result = processor.run();
+        assert!(result.is_end(), "The program must reach the end");
+        assert!(processor.is_stack_empty(), "The stack must be empty");
+    }
+
+    #[test]
+    fn test_assemble_branch_table() {
+        let source = "
+                const_32 1
+                branch_table zero, one, other
+            zero:
+                const_8 0x10
+                branch_rel end
+            one:
+                const_8 0x11
+                branch_rel end
+            other:
+                const_8 0x12
+            end:
+                nop";
+        let program = assemble(source).expect("The assembly must succeed");
+
+        assert_eq!(
+            &program.code()[5..22],
+            &[
+                Instruction::BranchTable as u8,
+                0x02,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x08,
+                0x00,
+                0x00,
+                0x00,
+                0x10,
+                0x00,
+                0x00,
+                0x00,
+            ],
+            "The table is incorrect"
+        );
+
+        verify(&program).expect("The verification must succeed");
+        assert_eq!(
+            disassemble_instruction(&program, 5),
+            Some((
+                "branch_table 0, 8, 16  ; 0x00000016, 0x0000001e, 0x00000026".to_string(),
+                17
+            )),
+            "The disassembly is incorrect"
+        );
+
+        let mut processor = Processor::new_empty(program, 20);
+        let result = processor.run();
+        assert!(result.is_end(), "The program must reach the end");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x11,
+            "The value of the stack is incorrect"
+        );
+        assert!(processor.is_stack_empty(), "The stack must be empty");
+    }
+
+    #[test]
+    fn test_assemble_extended() {
+        let program =
+            assemble("const_32 0x3f800000\npromote_f32_to_f64").expect("The assembly must succeed");
+
+        assert_eq!(
+            program.code(),
+            &[
+                Instruction::Const32 as u8,
+                0x00,
+                0x00,
+                0x80,
+                0x3f,
+                EXTENDED_OPCODE,
+                (Instruction::PromoteF32ToF64 as usize - 256) as u8,
+            ],
+            "The code is incorrect"
+        );
+
+        let mut processor = Processor::new_empty(program, 20);
+        let result = processor.run();
+        assert!(result.is_end(), "The program must reach the end");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            1.0,
+            "The value of the stack is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let cases = [
+            (
+                "nop\nfoo",
+                2,
+                AssemblerErrorKind::UnknownInstruction("foo".into()),
+            ),
+            (
+                ".text",
+                1,
+                AssemblerErrorKind::UnknownDirective(".text".into()),
+            ),
+            (
+                "a: nop\na: nop",
+                2,
+                AssemblerErrorKind::DuplicatedLabel("a".into()),
+            ),
+            (
+                "const_32 a",
+                1,
+                AssemblerErrorKind::UndefinedLabel("a".into()),
+            ),
+            (
+                "const_8 a",
+                1,
+                AssemblerErrorKind::InvalidOperand("a".into()),
+            ),
+            (
+                "const_8 256",
+                1,
+                AssemblerErrorKind::NumberOutOfRange("256".into()),
+            ),
+            (
+                "const_8 -129",
+                1,
+                AssemblerErrorKind::NumberOutOfRange("-129".into()),
+            ),
+            ("const_8", 1, AssemblerErrorKind::WrongOperandCount),
+            ("nop 1", 1, AssemblerErrorKind::WrongOperandCount),
+            (
+                ".data\nnop",
+                2,
+                AssemblerErrorKind::InstructionInDataSection,
+            ),
+            (".ascii \"abc", 1, AssemblerErrorKind::InvalidString),
+        ];
+
+        for (i, (source, line, kind)) in cases.iter().enumerate() {
+            let error = assemble(source).expect_err(&format!("[{}] The assembly must fail", i));
+            assert_eq!(error.line, *line, "[{}] The line is incorrect", i);
+            assert_eq!(&error.kind, kind, "[{}] The kind is incorrect", i);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+/// An entry of the call stack of a processor that records the state to restore when
+/// a subroutine returns.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CallFrame {
+    /// The code position of the instruction that follows the call.
+    pub return_address: usize,
+
+    /// The stack pointer when the subroutine was called. The subroutine must not go below it
+    /// before returning.
+    pub stack_pointer: usize,
+
+    /// The frame pointer of the caller.
+    pub frame_pointer: usize,
+}
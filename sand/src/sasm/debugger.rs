@@ -0,0 +1,339 @@
+use std::collections::BTreeSet;
+
+use crate::sasm::instructions::Instruction;
+use crate::sasm::{Action, Processor, Trap, Watchpoint, WatchpointHit};
+
+/// The reason why a debugger stopped the execution.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StopReason {
+    /// The requested step has been completed.
+    Step,
+
+    /// The program counter has reached a breakpoint. It contains its code position.
+    Breakpoint(usize),
+
+    /// The last executed instruction has accessed a watched memory range.
+    Watchpoint(WatchpointHit),
+
+    /// The processor has executed a `debug` instruction.
+    Halt,
+
+    /// The processor has raised a trap.
+    Trap(Trap),
+
+    /// The program counter has reached the end of the code.
+    End,
+
+    /// The processor has run out of fuel.
+    OutOfFuel,
+}
+
+/// A debugger that controls the execution of a processor with breakpoints, watchpoints
+/// and steps without modifying its program.
+pub struct Debugger {
+    processor: Processor,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl Debugger {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(processor: Processor) -> Debugger {
+        Debugger {
+            processor,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn processor(&self) -> &Processor {
+        &self.processor
+    }
+
+    #[inline]
+    pub fn processor_mut(&mut self) -> &mut Processor {
+        &mut self.processor
+    }
+
+    #[inline]
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    #[inline]
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        self.processor.memory().watchpoints()
+    }
+
+    /// Gets the instruction pointed by the program counter or `None` if it is unassigned
+    /// or outside the code.
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        let program = self.processor.program();
+        let code = program
+            .program()
+            .get(self.processor.program_counter()..program.code_pointer_end())?;
+
+        Instruction::read_index(code).and_then(Instruction::from_index)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_processor(self) -> Processor {
+        self.processor
+    }
+
+    /// Adds a breakpoint at `code_position` and returns whether it did not exist.
+    pub fn add_breakpoint(&mut self, code_position: usize) -> bool {
+        self.breakpoints.insert(code_position)
+    }
+
+    /// Removes the breakpoint at `code_position` and returns whether it existed.
+    pub fn remove_breakpoint(&mut self, code_position: usize) -> bool {
+        self.breakpoints.remove(&code_position)
+    }
+
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.processor.memory_mut().add_watchpoint(watchpoint);
+    }
+
+    /// Removes a watchpoint and returns whether it existed.
+    pub fn remove_watchpoint(&mut self, watchpoint: &Watchpoint) -> bool {
+        self.processor.memory_mut().remove_watchpoint(watchpoint)
+    }
+
+    /// Executes the instruction pointed by the program counter ignoring the breakpoints.
+    pub fn step(&mut self) -> StopReason {
+        // Discards the accesses made outside the debugger.
+        self.processor.memory().take_watchpoint_hit();
+
+        let result = self.processor.step();
+        match result {
+            Ok(()) => match self.processor.memory().take_watchpoint_hit() {
+                Some(hit) => StopReason::Watchpoint(hit),
+                None => StopReason::Step,
+            },
+            Err(Action::Halt) => StopReason::Halt,
+            Err(Action::Trap(trap)) => StopReason::Trap(trap),
+            Err(Action::End) => StopReason::End,
+            Err(Action::OutOfFuel) => StopReason::OutOfFuel,
+        }
+    }
+
+    /// Executes the instruction pointed by the program counter. If it is a call, the execution
+    /// continues until the subroutine returns, stopping before if anything else stops it.
+    pub fn step_over(&mut self) -> StopReason {
+        let call_depth = self.processor.call_depth();
+        let is_call = matches!(
+            self.current_instruction(),
+            Some(Instruction::Call) | Some(Instruction::CallRel)
+        );
+
+        let reason = self.step();
+        if !is_call || reason != StopReason::Step {
+            return reason;
+        }
+
+        self.run_until(|debugger| debugger.processor.call_depth() <= call_depth)
+    }
+
+    /// Continues the execution until a breakpoint or a watchpoint is hit, or the processor
+    /// halts, traps, runs out of fuel or reaches the end of the code. The breakpoint at
+    /// the current position, if any, is ignored so that the execution can continue after
+    /// stopping at it.
+    pub fn resume(&mut self) -> StopReason {
+        let reason = self.step();
+        if reason != StopReason::Step {
+            return reason;
+        }
+
+        self.run_until(|_| false)
+    }
+
+    fn run_until<F>(&mut self, condition: F) -> StopReason
+    where
+        F: Fn(&Debugger) -> bool,
+    {
+        loop {
+            if condition(self) {
+                return StopReason::Step;
+            }
+
+            let program_counter = self.processor.program_counter();
+            if self.breakpoints.contains(&program_counter) {
+                return StopReason::Breakpoint(program_counter);
+            }
+
+            let reason = self.step();
+            if reason != StopReason::Step {
+                return reason;
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::{Program, TrapKind, WatchpointKind};
+
+    use super::*;
+
+    fn create_debugger() -> Debugger {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const32 as u8,
+                0x0a,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Call as u8,
+                Instruction::Const8 as u8,
+                0x22,
+                Instruction::Debug as u8,
+                Instruction::Unreachable as u8,
+                Instruction::Const8 as u8,
+                0x11,
+                Instruction::Return as u8,
+            ],
+            0,
+            0,
+        );
+
+        Debugger::new(Processor::new_empty(program, 20))
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = create_debugger();
+        assert!(debugger.add_breakpoint(10), "The breakpoint must be new");
+
+        // Case 1
+        let reason = debugger.resume();
+        assert_eq!(
+            reason,
+            StopReason::Breakpoint(10),
+            "[1] The reason is incorrect"
+        );
+        assert_eq!(
+            debugger.processor().call_depth(),
+            1,
+            "[1] The call depth is incorrect"
+        );
+
+        // Case 2
+        let reason = debugger.step();
+        assert_eq!(reason, StopReason::Step, "[2] The reason is incorrect");
+        assert_eq!(
+            debugger.processor().program_counter(),
+            12,
+            "[2] The program counter is incorrect"
+        );
+
+        // Case 3
+        let reason = debugger.resume();
+        assert_eq!(reason, StopReason::Halt, "[3] The reason is incorrect");
+
+        // Case 4
+        let reason = debugger.resume();
+        assert_eq!(
+            reason,
+            StopReason::Trap(Trap {
+                program_counter: 9,
+                kind: TrapKind::Unreachable
+            }),
+            "[4] The reason is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_step_over() {
+        let mut debugger = create_debugger();
+
+        // Case 1
+        debugger.step();
+        let reason = debugger.step_over();
+        assert_eq!(reason, StopReason::Step, "[1] The reason is incorrect");
+        assert_eq!(
+            debugger.processor().program_counter(),
+            6,
+            "[1] The program counter is incorrect"
+        );
+        assert_eq!(
+            debugger.processor().call_depth(),
+            0,
+            "[1] The call depth is incorrect"
+        );
+
+        // Case 2
+        let mut debugger = create_debugger();
+        debugger.add_breakpoint(12);
+        debugger.step();
+        let reason = debugger.step_over();
+        assert_eq!(
+            reason,
+            StopReason::Breakpoint(12),
+            "[2] The reason is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_watchpoints() {
+        let mut debugger = create_debugger();
+        let read_watchpoint = Watchpoint::new(0, 4, WatchpointKind::Read);
+        let write_watchpoint = Watchpoint::new(1, 1, WatchpointKind::Write);
+        debugger.add_watchpoint(read_watchpoint);
+
+        // Case 1
+        let reason = debugger.resume();
+        assert_eq!(
+            reason,
+            StopReason::Watchpoint(WatchpointHit {
+                watchpoint: read_watchpoint,
+                address: 0,
+                size: 4,
+                is_write: false,
+            }),
+            "[1] The reason is incorrect"
+        );
+        assert_eq!(
+            debugger.processor().program_counter(),
+            10,
+            "[1] The program counter is incorrect"
+        );
+
+        // Case 2
+        debugger.add_watchpoint(write_watchpoint);
+        let reason = debugger.resume();
+        assert_eq!(
+            reason,
+            StopReason::Watchpoint(WatchpointHit {
+                watchpoint: write_watchpoint,
+                address: 1,
+                size: 1,
+                is_write: true,
+            }),
+            "[2] The reason is incorrect"
+        );
+        assert_eq!(
+            debugger.processor().program_counter(),
+            8,
+            "[2] The program counter is incorrect"
+        );
+
+        // Case 3
+        assert!(
+            debugger.remove_watchpoint(&write_watchpoint),
+            "[3] The watchpoint must exist"
+        );
+        assert_eq!(
+            debugger.watchpoints(),
+            &[read_watchpoint],
+            "[3] The watchpoints are incorrect"
+        );
+    }
+}
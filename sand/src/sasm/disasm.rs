@@ -0,0 +1,235 @@
+use std::convert::TryInto;
+use std::fmt::Write;
+
+use crate::sasm::instructions::{Instruction, EXTENDED_OPCODE};
+use crate::sasm::Program;
+
+/// The number of bytes per line of a hexdump.
+const HEXDUMP_LINE_SIZE: usize = 16;
+
+/// Disassembles a program: a hexdump of its data section followed by the listing
+/// of its code section with one instruction per line.
+pub fn disassemble(program: &Program) -> String {
+    let mut result = String::new();
+
+    result.push_str(".data\n");
+    result.push_str(&hexdump(program.data(), program.data_pointer()));
+
+    result.push_str(".code\n");
+    let mut position = program.code_pointer();
+    while let Some((text, size)) = disassemble_instruction(program, position) {
+        writeln!(result, "{:#010x}  {}", position, text).unwrap();
+        position += size;
+    }
+
+    result
+}
+
+/// Disassembles the instruction placed at `position` in the program and returns its text
+/// and its size in bytes, or `None` if the position is outside the code section.
+///
+/// Unassigned opcodes and truncated instructions are written as `.byte` directives
+/// followed by a comment explaining the problem. Relative branches are written with
+/// their signed offset followed by a comment with the target position. Branch tables are
+/// written the same way with all their entries, the default one at the end.
+pub fn disassemble_instruction(program: &Program, position: usize) -> Option<(String, usize)> {
+    if position < program.code_pointer() || position >= program.code_pointer_end() {
+        return None;
+    }
+
+    let code = &program.program()[position..program.code_pointer_end()];
+    let instruction = match Instruction::read_index(code).map(Instruction::from_index) {
+        Some(Some(v)) => v,
+        Some(None) => {
+            let size = if code[0] == EXTENDED_OPCODE { 2 } else { 1 };
+            let text = format!(".byte {}  ; unassigned opcode", format_bytes(&code[..size]));
+            return Some((text, size));
+        }
+        None => {
+            let text = format!(".byte {}  ; truncated opcode", format_bytes(code));
+            return Some((text, code.len()));
+        }
+    };
+
+    let size = match instruction.read_size(code) {
+        Some(v) => v,
+        None => {
+            let text = format!(
+                ".byte {}  ; truncated {}",
+                format_bytes(code),
+                instruction.name()
+            );
+            return Some((text, code.len()));
+        }
+    };
+
+    let mut text = instruction.name().to_string();
+    let immediate = &code[instruction.opcode_size()..size];
+    if instruction == Instruction::BranchTable {
+        let entry_size = std::mem::size_of::<i32>();
+        let (offsets, targets): (Vec<_>, Vec<_>) = immediate[entry_size..]
+            .chunks(entry_size)
+            .map(|bytes| {
+                let offset = i32::from_le_bytes(bytes.try_into().unwrap());
+                let target = (position + size) as i64 + offset as i64;
+                (offset.to_string(), format!("{:#010x}", target))
+            })
+            .unzip();
+        write!(text, " {}  ; {}", offsets.join(", "), targets.join(", ")).unwrap();
+    } else if instruction.is_relative_branch() {
+        let offset = i32::from_le_bytes(immediate.try_into().unwrap());
+        let target = (position + size) as i64 + offset as i64;
+        write!(text, " {}  ; {:#010x}", offset, target).unwrap();
+    } else if !immediate.is_empty() {
+        let value = immediate
+            .iter()
+            .rev()
+            .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+        write!(
+            text,
+            " {:#0width$x}",
+            value,
+            width = 2 + 2 * immediate.len()
+        )
+        .unwrap();
+    }
+
+    Some((text, size))
+}
+
+/// Writes `bytes` as a hexdump whose first line starts at `base_offset`.
+pub fn hexdump(bytes: &[u8], base_offset: usize) -> String {
+    let mut result = String::new();
+
+    for (i, line) in bytes.chunks(HEXDUMP_LINE_SIZE).enumerate() {
+        write!(result, "{:#010x} ", base_offset + i * HEXDUMP_LINE_SIZE).unwrap();
+
+        for j in 0..HEXDUMP_LINE_SIZE {
+            if j == HEXDUMP_LINE_SIZE / 2 {
+                result.push(' ');
+            }
+
+            match line.get(j) {
+                Some(v) => write!(result, " {:02x}", v).unwrap(),
+                None => result.push_str("   "),
+            }
+        }
+
+        result.push_str("  |");
+        for byte in line {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                result.push(*byte as char);
+            } else {
+                result.push('.');
+            }
+        }
+        result.push_str("|\n");
+    }
+
+    result
+}
+
+/// Writes `bytes` as a list of hexadecimal values separated by spaces.
+fn format_bytes(bytes: &[u8]) -> String {
+    let bytes: Vec<_> = bytes.iter().map(|v| format!("{:#04x}", v)).collect();
+    bytes.join(" ")
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        let program = Program::from_sections(
+            b"Sand",
+            &[
+                Instruction::Const32 as u8,
+                0x01,
+                0x23,
+                0x45,
+                0x67,
+                Instruction::Nop as u8,
+                0xfe,
+                EXTENDED_OPCODE,
+                (Instruction::PromoteF32ToF64 as usize - 256) as u8,
+                EXTENDED_OPCODE,
+                0xf0,
+                Instruction::Const16 as u8,
+                0x01,
+            ],
+        );
+
+        let result = disassemble(&program);
+        assert_eq!(
+            result,
+            ".data\n\
+             0x00000000  53 61 6e 64                                       |Sand|\n\
+             .code\n\
+             0x00000004  const_32 0x67452301\n\
+             0x00000009  nop\n\
+             0x0000000a  .byte 0xfe  ; unassigned opcode\n\
+             0x0000000b  promote_f32_to_f64\n\
+             0x0000000d  .byte 0xff 0xf0  ; unassigned opcode\n\
+             0x0000000f  .byte 0x2d 0x01  ; truncated const_16\n",
+            "The listing is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_disassemble_instruction() {
+        let program = Program::from_sections(&[0x00], &[Instruction::Const8 as u8, 0x7f]);
+
+        // Case 1
+        let result = disassemble_instruction(&program, 1);
+        assert_eq!(
+            result,
+            Some(("const_8 0x7f".to_string(), 2)),
+            "[1] The instruction is incorrect"
+        );
+
+        // Case 2
+        let result = disassemble_instruction(&program, 0);
+        assert_eq!(result, None, "[2] The data cannot be disassembled");
+
+        // Case 3
+        let result = disassemble_instruction(&program, 3);
+        assert_eq!(result, None, "[3] The end cannot be disassembled");
+
+        // Case 4
+        let program = Program::from_sections(
+            &[0x00],
+            &[
+                EXTENDED_OPCODE,
+                (Instruction::BranchRel as usize - 256) as u8,
+                0xfa,
+                0xff,
+                0xff,
+                0xff,
+            ],
+        );
+        let result = disassemble_instruction(&program, 1);
+        assert_eq!(
+            result,
+            Some(("branch_rel -6  ; 0x00000001".to_string(), 6)),
+            "[4] The instruction is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let bytes: Vec<u8> = (0x30..0x48).collect();
+        let result = hexdump(&bytes, 0x10);
+
+        assert_eq!(
+            result,
+            "0x00000010  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|\n\
+             0x00000020  40 41 42 43 44 45 46 47                           |@ABCDEFG|\n",
+            "The hexdump is incorrect"
+        );
+    }
+}
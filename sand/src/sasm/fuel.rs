@@ -0,0 +1,85 @@
+use crate::sasm::instructions::{Instruction, EXTENDED_INSTRUCTION_LIST, INSTRUCTION_LIST};
+
+/// The fuel an instruction consumes by default.
+pub const FUEL_DEFAULT_COST: u64 = 1;
+
+/// The fuel each instruction consumes when a processor executes it, indexed by opcode.
+/// Unassigned opcodes consume the default cost before raising their trap.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FuelCostTable {
+    costs: Vec<u64>,
+}
+
+impl FuelCostTable {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a table where every instruction costs `FUEL_DEFAULT_COST`.
+    pub fn new() -> FuelCostTable {
+        FuelCostTable::with_cost(FUEL_DEFAULT_COST)
+    }
+
+    /// Creates a table where every instruction costs `cost`.
+    pub fn with_cost(cost: u64) -> FuelCostTable {
+        FuelCostTable {
+            costs: vec![cost; INSTRUCTION_LIST.len() + EXTENDED_INSTRUCTION_LIST.len()],
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn cost(&self, instruction: Instruction) -> u64 {
+        self.costs[instruction.index()]
+    }
+
+    /// Gets the cost of the opcode `index`, which can be unassigned.
+    #[inline]
+    pub fn cost_of_index(&self, index: usize) -> u64 {
+        self.costs[index]
+    }
+
+    // SETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn set_cost(&mut self, instruction: Instruction, cost: u64) {
+        self.costs[instruction.index()] = cost
+    }
+}
+
+impl Default for FuelCostTable {
+    fn default() -> Self {
+        FuelCostTable::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cost_table() {
+        let mut table = FuelCostTable::new();
+        table.set_cost(Instruction::CallHost, 10);
+        table.set_cost(Instruction::PromoteF32ToF64, 3);
+
+        assert_eq!(
+            table.cost(Instruction::Nop),
+            FUEL_DEFAULT_COST,
+            "[1] The cost is incorrect"
+        );
+        assert_eq!(
+            table.cost(Instruction::CallHost),
+            10,
+            "[2] The cost is incorrect"
+        );
+        assert_eq!(
+            table.cost_of_index(Instruction::PromoteF32ToF64.index()),
+            3,
+            "[3] The cost is incorrect"
+        );
+    }
+}
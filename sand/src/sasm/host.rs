@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use crate::sasm::{Action, Processor};
+
+/// A function of the host application that programs can call with the `call_host` instruction.
+/// It uses the same stack conventions as the instructions. Functions are `Send + Sync` so that
+/// processors can be moved to other threads.
+pub type HostFunction = Arc<dyn Fn(&mut Processor) -> Result<(), Action> + Send + Sync>;
+
+/// The registry of host functions of a processor, addressed by index or by name.
+#[derive(Default, Clone)]
+pub struct HostFunctions {
+    functions: Vec<(String, HostFunction)>,
+}
+
+impl HostFunctions {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> HostFunctions {
+        HostFunctions::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    /// Gets the function registered at `index`.
+    pub fn get(&self, index: u32) -> Option<&HostFunction> {
+        self.functions.get(index as usize).map(|(_, v)| v)
+    }
+
+    /// Gets the name of the function registered at `index`.
+    pub fn name(&self, index: u32) -> Option<&str> {
+        self.functions.get(index as usize).map(|(v, _)| v.as_str())
+    }
+
+    /// Gets the index of the function registered with `name`.
+    pub fn index_of(&self, name: &str) -> Option<u32> {
+        self.functions
+            .iter()
+            .position(|(v, _)| v == name)
+            .map(|v| v as u32)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Registers `function` with `name` and returns its index. If `name` is already
+    /// registered, its function is replaced keeping the same index.
+    pub fn register<F>(&mut self, name: &str, function: F) -> u32
+    where
+        F: Fn(&mut Processor) -> Result<(), Action> + Send + Sync + 'static,
+    {
+        let function: HostFunction = Arc::new(function);
+        match self.index_of(name) {
+            Some(index) => {
+                self.functions[index as usize].1 = function;
+                index
+            }
+            None => {
+                self.functions.push((name.to_string(), function));
+                (self.functions.len() - 1) as u32
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let mut functions = HostFunctions::new();
+
+        // Case 1
+        let index = functions.register("first", |_| Ok(()));
+        assert_eq!(index, 0, "[1] The index is incorrect");
+
+        // Case 2
+        let index = functions.register("second", |_| Err(Action::Halt));
+        assert_eq!(index, 1, "[2] The index is incorrect");
+        assert_eq!(
+            functions.name(1),
+            Some("second"),
+            "[2] The name is incorrect"
+        );
+
+        // Case 3
+        let index = functions.register("first", |_| Err(Action::End));
+        assert_eq!(index, 0, "[3] The index is incorrect");
+        assert_eq!(functions.len(), 2, "[3] The length is incorrect");
+        assert_eq!(
+            functions.index_of("first"),
+            Some(0),
+            "[3] The lookup is incorrect"
+        );
+        assert_eq!(
+            functions.index_of("third"),
+            None,
+            "[3] The lookup is incorrect"
+        );
+        assert!(
+            functions.get(2).is_none(),
+            "[3] The index must be unassigned"
+        );
+    }
+}
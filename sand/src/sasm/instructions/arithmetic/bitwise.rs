@@ -0,0 +1,922 @@
+use crate::sasm::{Action, Processor};
+
+/// Pops two ?8 values from the stack and pushes their bitwise and.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn and_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = left & right;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their bitwise and.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn and_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = left & right;
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their bitwise and.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn and_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = left & right;
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their bitwise and.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn and_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = left & right;
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?8 values from the stack and pushes their bitwise or.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn or_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = left | right;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their bitwise or.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn or_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = left | right;
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their bitwise or.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn or_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = left | right;
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their bitwise or.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn or_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = left | right;
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?8 values from the stack and pushes their bitwise exclusive or.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn xor_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = left ^ right;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their bitwise exclusive or.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn xor_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = left ^ right;
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their bitwise exclusive or.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn xor_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = left ^ right;
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their bitwise exclusive or.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn xor_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = left ^ right;
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value from the stack and pushes its bitwise negation.
+///
+/// Stack:
+/// - ?8 - Input value.
+/// + ?8 - Output value.
+pub fn not_8(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u8()?;
+    let output_value = !input_value;
+
+    processor.push_u8(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value from the stack and pushes its bitwise negation.
+///
+/// Stack:
+/// - ?16 - Input value.
+/// + ?16 - Output value.
+pub fn not_16(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u16()?;
+    let output_value = !input_value;
+
+    processor.push_u16(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and pushes its bitwise negation.
+///
+/// Stack:
+/// - ?32 - Input value.
+/// + ?32 - Output value.
+pub fn not_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = !input_value;
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and pushes its bitwise negation.
+///
+/// Stack:
+/// - ?64 - Input value.
+/// + ?64 - Output value.
+pub fn not_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = !input_value;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value and a shift amount from the stack, shifts the value to the left and
+/// pushes the result.
+/// The shift amount is taken modulo 8.
+///
+/// Stack:
+/// - ?8 - Shift amount.
+/// - ?8 - Value.
+/// + ?8 - Result.
+pub fn shl_8(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u8()?;
+    let value = processor.pop_u8()?;
+    let result = value.wrapping_shl(amount as u32);
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value and a shift amount from the stack, shifts the value to the left and
+/// pushes the result.
+/// The shift amount is taken modulo 16.
+///
+/// Stack:
+/// - ?16 - Shift amount.
+/// - ?16 - Value.
+/// + ?16 - Result.
+pub fn shl_16(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u16()?;
+    let value = processor.pop_u16()?;
+    let result = value.wrapping_shl(amount as u32);
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value and a shift amount from the stack, shifts the value to the left and
+/// pushes the result.
+/// The shift amount is taken modulo 32.
+///
+/// Stack:
+/// - ?32 - Shift amount.
+/// - ?32 - Value.
+/// + ?32 - Result.
+pub fn shl_32(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u32()?;
+    let value = processor.pop_u32()?;
+    let result = value.wrapping_shl(amount);
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value and a shift amount from the stack, shifts the value to the left and
+/// pushes the result.
+/// The shift amount is taken modulo 64.
+///
+/// Stack:
+/// - ?64 - Shift amount.
+/// - ?64 - Value.
+/// + ?64 - Result.
+pub fn shl_64(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u64()?;
+    let value = processor.pop_u64()?;
+    let result = value.wrapping_shl(amount as u32);
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops a u8 value and a shift amount from the stack, shifts the value to the right
+/// filling with zeros and pushes the result.
+/// The shift amount is taken modulo 8.
+///
+/// Stack:
+/// - ?8 - Shift amount.
+/// - u8 - Value.
+/// + u8 - Result.
+pub fn shr_8(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u8()?;
+    let value = processor.pop_u8()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a u16 value and a shift amount from the stack, shifts the value to the right
+/// filling with zeros and pushes the result.
+/// The shift amount is taken modulo 16.
+///
+/// Stack:
+/// - ?16 - Shift amount.
+/// - u16 - Value.
+/// + u16 - Result.
+pub fn shr_16(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u16()?;
+    let value = processor.pop_u16()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops a u32 value and a shift amount from the stack, shifts the value to the right
+/// filling with zeros and pushes the result.
+/// The shift amount is taken modulo 32.
+///
+/// Stack:
+/// - ?32 - Shift amount.
+/// - u32 - Value.
+/// + u32 - Result.
+pub fn shr_32(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u32()?;
+    let value = processor.pop_u32()?;
+    let result = value.wrapping_shr(amount);
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops a u64 value and a shift amount from the stack, shifts the value to the right
+/// filling with zeros and pushes the result.
+/// The shift amount is taken modulo 64.
+///
+/// Stack:
+/// - ?64 - Shift amount.
+/// - u64 - Value.
+/// + u64 - Result.
+pub fn shr_64(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u64()?;
+    let value = processor.pop_u64()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops an i8 value and a shift amount from the stack, shifts the value to the right
+/// filling with its sign bit and pushes the result.
+/// The shift amount is taken modulo 8.
+///
+/// Stack:
+/// - ?8 - Shift amount.
+/// - i8 - Value.
+/// + i8 - Result.
+pub fn shr_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u8()?;
+    let value = processor.pop_i8()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops an i16 value and a shift amount from the stack, shifts the value to the right
+/// filling with its sign bit and pushes the result.
+/// The shift amount is taken modulo 16.
+///
+/// Stack:
+/// - ?16 - Shift amount.
+/// - i16 - Value.
+/// + i16 - Result.
+pub fn shr_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u16()?;
+    let value = processor.pop_i16()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops an i32 value and a shift amount from the stack, shifts the value to the right
+/// filling with its sign bit and pushes the result.
+/// The shift amount is taken modulo 32.
+///
+/// Stack:
+/// - ?32 - Shift amount.
+/// - i32 - Value.
+/// + i32 - Result.
+pub fn shr_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u32()?;
+    let value = processor.pop_i32()?;
+    let result = value.wrapping_shr(amount);
+
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops an i64 value and a shift amount from the stack, shifts the value to the right
+/// filling with its sign bit and pushes the result.
+/// The shift amount is taken modulo 64.
+///
+/// Stack:
+/// - ?64 - Shift amount.
+/// - i64 - Value.
+/// + i64 - Result.
+pub fn shr_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u64()?;
+    let value = processor.pop_i64()?;
+    let result = value.wrapping_shr(amount as u32);
+
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value and a rotation amount from the stack, rotates the value to the left
+/// and pushes the result.
+/// The rotation amount is taken modulo 8.
+///
+/// Stack:
+/// - ?8 - Rotation amount.
+/// - ?8 - Value.
+/// + ?8 - Result.
+pub fn rotl_8(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u8()?;
+    let value = processor.pop_u8()?;
+    let result = value.rotate_left(amount as u32);
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value and a rotation amount from the stack, rotates the value to the left
+/// and pushes the result.
+/// The rotation amount is taken modulo 16.
+///
+/// Stack:
+/// - ?16 - Rotation amount.
+/// - ?16 - Value.
+/// + ?16 - Result.
+pub fn rotl_16(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u16()?;
+    let value = processor.pop_u16()?;
+    let result = value.rotate_left(amount as u32);
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value and a rotation amount from the stack, rotates the value to the left
+/// and pushes the result.
+/// The rotation amount is taken modulo 32.
+///
+/// Stack:
+/// - ?32 - Rotation amount.
+/// - ?32 - Value.
+/// + ?32 - Result.
+pub fn rotl_32(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u32()?;
+    let value = processor.pop_u32()?;
+    let result = value.rotate_left(amount);
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value and a rotation amount from the stack, rotates the value to the left
+/// and pushes the result.
+/// The rotation amount is taken modulo 64.
+///
+/// Stack:
+/// - ?64 - Rotation amount.
+/// - ?64 - Value.
+/// + ?64 - Result.
+pub fn rotl_64(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u64()?;
+    let value = processor.pop_u64()?;
+    let result = value.rotate_left(amount as u32);
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value and a rotation amount from the stack, rotates the value to the right
+/// and pushes the result.
+/// The rotation amount is taken modulo 8.
+///
+/// Stack:
+/// - ?8 - Rotation amount.
+/// - ?8 - Value.
+/// + ?8 - Result.
+pub fn rotr_8(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u8()?;
+    let value = processor.pop_u8()?;
+    let result = value.rotate_right(amount as u32);
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value and a rotation amount from the stack, rotates the value to the right
+/// and pushes the result.
+/// The rotation amount is taken modulo 16.
+///
+/// Stack:
+/// - ?16 - Rotation amount.
+/// - ?16 - Value.
+/// + ?16 - Result.
+pub fn rotr_16(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u16()?;
+    let value = processor.pop_u16()?;
+    let result = value.rotate_right(amount as u32);
+
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value and a rotation amount from the stack, rotates the value to the right
+/// and pushes the result.
+/// The rotation amount is taken modulo 32.
+///
+/// Stack:
+/// - ?32 - Rotation amount.
+/// - ?32 - Value.
+/// + ?32 - Result.
+pub fn rotr_32(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u32()?;
+    let value = processor.pop_u32()?;
+    let result = value.rotate_right(amount);
+
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value and a rotation amount from the stack, rotates the value to the right
+/// and pushes the result.
+/// The rotation amount is taken modulo 64.
+///
+/// Stack:
+/// - ?64 - Rotation amount.
+/// - ?64 - Value.
+/// + ?64 - Result.
+pub fn rotr_64(processor: &mut Processor) -> Result<(), Action> {
+    let amount = processor.pop_u64()?;
+    let value = processor.pop_u64()?;
+    let result = value.rotate_right(amount as u32);
+
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value from the stack and pushes the number of bits set to one.
+///
+/// Stack:
+/// - ?8 - Input value.
+/// + u8 - Number of bits.
+pub fn popcnt_8(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u8()?;
+    let output_value = input_value.count_ones() as u8;
+
+    processor.push_u8(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value from the stack and pushes the number of bits set to one.
+///
+/// Stack:
+/// - ?16 - Input value.
+/// + u16 - Number of bits.
+pub fn popcnt_16(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u16()?;
+    let output_value = input_value.count_ones() as u16;
+
+    processor.push_u16(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and pushes the number of bits set to one.
+///
+/// Stack:
+/// - ?32 - Input value.
+/// + u32 - Number of bits.
+pub fn popcnt_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = input_value.count_ones();
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and pushes the number of bits set to one.
+///
+/// Stack:
+/// - ?64 - Input value.
+/// + u64 - Number of bits.
+pub fn popcnt_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = input_value.count_ones() as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value from the stack and pushes its number of leading zero bits.
+///
+/// Stack:
+/// - ?8 - Input value.
+/// + u8 - Number of bits.
+pub fn clz_8(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u8()?;
+    let output_value = input_value.leading_zeros() as u8;
+
+    processor.push_u8(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value from the stack and pushes its number of leading zero bits.
+///
+/// Stack:
+/// - ?16 - Input value.
+/// + u16 - Number of bits.
+pub fn clz_16(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u16()?;
+    let output_value = input_value.leading_zeros() as u16;
+
+    processor.push_u16(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and pushes its number of leading zero bits.
+///
+/// Stack:
+/// - ?32 - Input value.
+/// + u32 - Number of bits.
+pub fn clz_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = input_value.leading_zeros();
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and pushes its number of leading zero bits.
+///
+/// Stack:
+/// - ?64 - Input value.
+/// + u64 - Number of bits.
+pub fn clz_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = input_value.leading_zeros() as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value from the stack and pushes its number of trailing zero bits.
+///
+/// Stack:
+/// - ?8 - Input value.
+/// + u8 - Number of bits.
+pub fn ctz_8(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u8()?;
+    let output_value = input_value.trailing_zeros() as u8;
+
+    processor.push_u8(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value from the stack and pushes its number of trailing zero bits.
+///
+/// Stack:
+/// - ?16 - Input value.
+/// + u16 - Number of bits.
+pub fn ctz_16(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u16()?;
+    let output_value = input_value.trailing_zeros() as u16;
+
+    processor.push_u16(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and pushes its number of trailing zero bits.
+///
+/// Stack:
+/// - ?32 - Input value.
+/// + u32 - Number of bits.
+pub fn ctz_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = input_value.trailing_zeros();
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and pushes its number of trailing zero bits.
+///
+/// Stack:
+/// - ?64 - Input value.
+/// + u64 - Number of bits.
+pub fn ctz_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = input_value.trailing_zeros() as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_logic() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_u8(0b1100).unwrap();
+        processor.push_u8(0b1010).unwrap();
+        and_8(&mut processor).expect("[1] The and must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0b1000,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_u16(0b1100).unwrap();
+        processor.push_u16(0b1010).unwrap();
+        or_16(&mut processor).expect("[2] The or must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0b1110,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_u32(0b1100).unwrap();
+        processor.push_u32(0b1010).unwrap();
+        xor_32(&mut processor).expect("[3] The xor must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0b0110,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_u64(0x00ff_00ff_00ff_00ff).unwrap();
+        not_64(&mut processor).expect("[4] The not must succeed");
+        assert_eq!(
+            processor.pop_u64().unwrap(),
+            0xff00_ff00_ff00_ff00,
+            "[4] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_shift_rotate() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_u8(0x81).unwrap();
+        processor.push_u8(9).unwrap();
+        shl_8(&mut processor).expect("[1] The shl must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x02,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_u16(0x8000).unwrap();
+        processor.push_u16(15).unwrap();
+        shr_16(&mut processor).expect("[2] The shr must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x0001,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_i32(-16).unwrap();
+        processor.push_u32(2).unwrap();
+        shr_sign_32(&mut processor).expect("[3] The shr must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            -4,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_u64(0x8000_0000_0000_0001).unwrap();
+        processor.push_u64(4).unwrap();
+        rotl_64(&mut processor).expect("[4] The rotl must succeed");
+        assert_eq!(
+            processor.pop_u64().unwrap(),
+            0x0000_0000_0000_0018,
+            "[4] The result is incorrect"
+        );
+
+        // Case 5
+        processor.push_u8(0x01).unwrap();
+        processor.push_u8(1).unwrap();
+        rotr_8(&mut processor).expect("[5] The rotr must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x80,
+            "[5] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_u16(0b1011_0000).unwrap();
+        popcnt_16(&mut processor).expect("[1] The popcnt must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            3,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_u32(0x0000_ff00).unwrap();
+        clz_32(&mut processor).expect("[2] The clz must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            16,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_u64(0).unwrap();
+        ctz_64(&mut processor).expect("[3] The ctz must succeed");
+        assert_eq!(
+            processor.pop_u64().unwrap(),
+            64,
+            "[3] The result is incorrect"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use crate::sasm::{Action, Processor};
+use crate::sasm::{Action, Processor, TrapKind};
 
 /// Pops a ?8 value from the stack, extends it to a u16 value.
 ///
@@ -259,3 +259,616 @@ pub fn trunc_64_to_32(processor: &mut Processor) -> Result<(), Action> {
 
     Ok(())
 }
+
+/// Pops a u32 value from the stack, converts it to the nearest f32 value and pushes it.
+///
+/// Stack:
+/// - u32 - Input value.
+/// + f32 - Output value.
+pub fn convert_32_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = input_value as f32;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a i32 value from the stack, converts it to the nearest f32 value and pushes it.
+///
+/// Stack:
+/// - i32 - Input value.
+/// + f32 - Output value.
+pub fn convert_sign_32_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_i32()?;
+    let output_value = input_value as f32;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a u32 value from the stack, converts it to the nearest f64 value and pushes it.
+///
+/// Stack:
+/// - u32 - Input value.
+/// + f64 - Output value.
+pub fn convert_32_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = input_value as f64;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a i32 value from the stack, converts it to the nearest f64 value and pushes it.
+///
+/// Stack:
+/// - i32 - Input value.
+/// + f64 - Output value.
+pub fn convert_sign_32_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_i32()?;
+    let output_value = input_value as f64;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a u64 value from the stack, converts it to the nearest f32 value and pushes it.
+///
+/// Stack:
+/// - u64 - Input value.
+/// + f32 - Output value.
+pub fn convert_64_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = input_value as f32;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a i64 value from the stack, converts it to the nearest f32 value and pushes it.
+///
+/// Stack:
+/// - i64 - Input value.
+/// + f32 - Output value.
+pub fn convert_sign_64_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_i64()?;
+    let output_value = input_value as f32;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a u64 value from the stack, converts it to the nearest f64 value and pushes it.
+///
+/// Stack:
+/// - u64 - Input value.
+/// + f64 - Output value.
+pub fn convert_64_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = input_value as f64;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a i64 value from the stack, converts it to the nearest f64 value and pushes it.
+///
+/// Stack:
+/// - i64 - Input value.
+/// + f64 - Output value.
+pub fn convert_sign_64_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_i64()?;
+    let output_value = input_value as f64;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a u32 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of u32.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + u32 - Output value.
+pub fn trunc_f32_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = check_truncation(input_value as f64, 0.0, 4294967296.0)? as u32;
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a i32 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of i32.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + i32 - Output value.
+pub fn trunc_sign_f32_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = check_truncation(input_value as f64, -2147483648.0, 2147483648.0)? as i32;
+
+    processor.push_i32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a u64 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of u64.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + u64 - Output value.
+pub fn trunc_f32_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = check_truncation(input_value as f64, 0.0, 18446744073709551616.0)? as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a i64 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of i64.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + i64 - Output value.
+pub fn trunc_sign_f32_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = check_truncation(
+        input_value as f64,
+        -9223372036854775808.0,
+        9223372036854775808.0,
+    )? as i64;
+
+    processor.push_i64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a u32 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of u32.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + u32 - Output value.
+pub fn trunc_f64_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = check_truncation(input_value, 0.0, 4294967296.0)? as u32;
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a i32 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of i32.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + i32 - Output value.
+pub fn trunc_sign_f64_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = check_truncation(input_value, -2147483648.0, 2147483648.0)? as i32;
+
+    processor.push_i32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a u64 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of u64.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + u64 - Output value.
+pub fn trunc_f64_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = check_truncation(input_value, 0.0, 18446744073709551616.0)? as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a i64 value and pushes it
+/// to the stack.
+/// Can cause a trap when the value is NaN or it is out of the range of i64.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + i64 - Output value.
+pub fn trunc_sign_f64_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value =
+        check_truncation(input_value, -9223372036854775808.0, 9223372036854775808.0)? as i64;
+
+    processor.push_i64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a u32 value and pushes it
+/// to the stack.
+/// Values out of the range of u32 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + u32 - Output value.
+pub fn trunc_sat_f32_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value as u32;
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a i32 value and pushes it
+/// to the stack.
+/// Values out of the range of i32 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + i32 - Output value.
+pub fn trunc_sat_sign_f32_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value as i32;
+
+    processor.push_i32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a u64 value and pushes it
+/// to the stack.
+/// Values out of the range of u64 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + u64 - Output value.
+pub fn trunc_sat_f32_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, truncates it towards zero to a i64 value and pushes it
+/// to the stack.
+/// Values out of the range of i64 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + i64 - Output value.
+pub fn trunc_sat_sign_f32_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value as i64;
+
+    processor.push_i64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a u32 value and pushes it
+/// to the stack.
+/// Values out of the range of u32 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + u32 - Output value.
+pub fn trunc_sat_f64_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value as u32;
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a i32 value and pushes it
+/// to the stack.
+/// Values out of the range of i32 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + i32 - Output value.
+pub fn trunc_sat_sign_f64_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value as i32;
+
+    processor.push_i32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a u64 value and pushes it
+/// to the stack.
+/// Values out of the range of u64 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + u64 - Output value.
+pub fn trunc_sat_f64_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value as u64;
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, truncates it towards zero to a i64 value and pushes it
+/// to the stack.
+/// Values out of the range of i64 saturate to its bounds and NaN is converted to 0.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + i64 - Output value.
+pub fn trunc_sat_sign_f64_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value as i64;
+
+    processor.push_i64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack, converts it exactly to a f64 value and pushes it to the stack.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f64 - Output value.
+pub fn promote_f32_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value as f64;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack, converts it to the nearest f32 value and pushes it.
+/// Values out of the range of f32 become infinities and NaN stays NaN.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f32 - Output value.
+pub fn demote_f64_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value as f32;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes its bits as a ?32 value.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + ?32 - Output value.
+pub fn reinterpret_f32_to_32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.to_bits();
+
+    processor.push_u32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes its bits as a ?64 value.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + ?64 - Output value.
+pub fn reinterpret_f64_to_64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.to_bits();
+
+    processor.push_u64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and pushes the f32 value with the same bits.
+///
+/// Stack:
+/// - ?32 - Input value.
+/// + f32 - Output value.
+pub fn reinterpret_32_to_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u32()?;
+    let output_value = f32::from_bits(input_value);
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and pushes the f64 value with the same bits.
+///
+/// Stack:
+/// - ?64 - Input value.
+/// + f64 - Output value.
+pub fn reinterpret_64_to_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_u64()?;
+    let output_value = f64::from_bits(input_value);
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Truncates `value` towards zero checking that the result is in the range [min, max).
+fn check_truncation(value: f64, min: f64, max: f64) -> Result<f64, Action> {
+    if value.is_nan() {
+        return Err(Action::trap(TrapKind::InvalidConversionToInteger));
+    }
+
+    let truncated = value.trunc();
+    if truncated < min || truncated >= max {
+        return Err(Action::trap(TrapKind::IntegerOverflow));
+    }
+
+    Ok(truncated)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        processor.push_u32(u32::MAX).unwrap();
+        convert_32_to_f64(&mut processor).expect("[1] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            4294967295.0,
+            "[1] The result is incorrect"
+        );
+
+        processor.push_i32(-7).unwrap();
+        convert_sign_32_to_f32(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            -7.0,
+            "[2] The result is incorrect"
+        );
+
+        processor.push_i64(-1).unwrap();
+        convert_64_to_f64(&mut processor).expect("[3] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            18446744073709551616.0,
+            "[3] The result is incorrect"
+        );
+
+        processor.push_f32(-1.5).unwrap();
+        promote_f32_to_f64(&mut processor).expect("[4] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -1.5,
+            "[4] The result is incorrect"
+        );
+
+        processor.push_f64(1e300).unwrap();
+        demote_f64_to_f32(&mut processor).expect("[5] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            f32::INFINITY,
+            "[5] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_trunc() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        processor.push_f32(-0.75).unwrap();
+        trunc_f32_to_32(&mut processor).expect("[1] The method must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0,
+            "[1] The result is incorrect"
+        );
+
+        processor.push_f64(-2147483648.9).unwrap();
+        trunc_sign_f64_to_32(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            i32::MIN,
+            "[2] The result is incorrect"
+        );
+
+        processor.push_f64(2147483648.0).unwrap();
+        let error = trunc_sign_f64_to_32(&mut processor).expect_err("[3] The method must fail");
+        assert_eq!(
+            error.unwrap_trap().kind,
+            TrapKind::IntegerOverflow,
+            "[3] The error is incorrect"
+        );
+
+        processor.push_f32(-1.0).unwrap();
+        let error = trunc_f32_to_64(&mut processor).expect_err("[4] The method must fail");
+        assert_eq!(
+            error.unwrap_trap().kind,
+            TrapKind::IntegerOverflow,
+            "[4] The error is incorrect"
+        );
+
+        processor.push_f64(f64::NAN).unwrap();
+        let error = trunc_f64_to_64(&mut processor).expect_err("[5] The method must fail");
+        assert_eq!(
+            error.unwrap_trap().kind,
+            TrapKind::InvalidConversionToInteger,
+            "[5] The error is incorrect"
+        );
+
+        processor.push_f32(-1e20).unwrap();
+        trunc_sat_sign_f32_to_64(&mut processor).expect("[6] The method must succeed");
+        assert_eq!(
+            processor.pop_i64().unwrap(),
+            i64::MIN,
+            "[6] The result is incorrect"
+        );
+
+        processor.push_f64(f64::NAN).unwrap();
+        trunc_sat_f64_to_32(&mut processor).expect("[7] The method must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0,
+            "[7] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_reinterpret() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        processor.push_f32(1.0).unwrap();
+        reinterpret_f32_to_32(&mut processor).expect("[1] The method must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x3f80_0000,
+            "[1] The result is incorrect"
+        );
+
+        processor.push_u64(0xbff0_0000_0000_0000).unwrap();
+        reinterpret_64_to_f64(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -1.0,
+            "[2] The result is incorrect"
+        );
+    }
+}
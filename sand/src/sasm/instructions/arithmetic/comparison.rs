@@ -0,0 +1,1058 @@
+use crate::sasm::{Action, Processor};
+
+/// Pops two ?8 values from the stack and checks whether they are equal.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and checks whether they are equal.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and checks whether they are equal.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and checks whether they are equal.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?8 values from the stack and checks whether they are different.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and checks whether they are different.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and checks whether they are different.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and checks whether they are different.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?8 value from the stack and checks whether it is zero.
+///
+/// Stack:
+/// - ?8 - Value.
+/// + u8 - 1 if the value is zero, 0 otherwise.
+pub fn eqz_8(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.pop_u8()?;
+    let result = (value == 0) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?16 value from the stack and checks whether it is zero.
+///
+/// Stack:
+/// - ?16 - Value.
+/// + u8 - 1 if the value is zero, 0 otherwise.
+pub fn eqz_16(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.pop_u16()?;
+    let result = (value == 0) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?32 value from the stack and checks whether it is zero.
+///
+/// Stack:
+/// - ?32 - Value.
+/// + u8 - 1 if the value is zero, 0 otherwise.
+pub fn eqz_32(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.pop_u32()?;
+    let result = (value == 0) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops a ?64 value from the stack and checks whether it is zero.
+///
+/// Stack:
+/// - ?64 - Value.
+/// + u8 - 1 if the value is zero, 0 otherwise.
+pub fn eqz_64(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.pop_u64()?;
+    let result = (value == 0) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and checks whether the left one is lower than
+/// the right one.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and checks whether the left one is greater than
+/// the right one.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether they are equal.
+/// If any operand is NaN the result is 0. Zeros are equal regardless of their sign.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether they are equal.
+/// If any operand is NaN the result is 0. Zeros are equal regardless of their sign.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn eq_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left == right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether they are different.
+/// If any operand is NaN the result is 1. Zeros are equal regardless of their sign.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether they are different.
+/// If any operand is NaN the result is 1. Zeros are equal regardless of their sign.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ne_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left != right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether the left one is lower than
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether the left one is lower than
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn lt_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left < right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether the left one is lower than or equal to
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn le_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left <= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether the left one is greater than
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether the left one is greater than
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn gt_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left > right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and checks whether the left one is greater than or equal to
+/// the right one.
+/// If any operand is NaN the result is 0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + u8 - 1 if the condition holds, 0 otherwise.
+pub fn ge_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = (left >= right) as u8;
+
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::instructions::{branch_if_8, InstructionFunction};
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_integer_comparison() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        let cases: [(InstructionFunction, u8); 8] = [
+            (eq_8, 0),
+            (ne_8, 1),
+            (lt_8, 0),
+            (lt_sign_8, 1),
+            (le_8, 0),
+            (gt_8, 1),
+            (gt_sign_8, 0),
+            (ge_sign_8, 0),
+        ];
+
+        for (i, (method, expected)) in cases.iter().enumerate() {
+            processor.push_i8(-1).unwrap();
+            processor.push_i8(1).unwrap();
+            method(&mut processor).unwrap_or_else(|_| panic!("[{}] The method must succeed", i));
+            assert_eq!(
+                processor.pop_u8().unwrap(),
+                *expected,
+                "[{}] The result is incorrect",
+                i
+            );
+            assert!(
+                processor.is_stack_empty(),
+                "[{}] The stack must be empty",
+                i
+            );
+        }
+
+        processor.push_u64(0).unwrap();
+        eqz_64(&mut processor).expect("The eqz must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            1,
+            "The eqz result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_float_comparison() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_f32(-0.0).unwrap();
+        processor.push_f32(0.0).unwrap();
+        eq_f32(&mut processor).expect("[1] The eq must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            1,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_f64(1.5).unwrap();
+        processor.push_f64(2.5).unwrap();
+        lt_f64(&mut processor).expect("[2] The lt must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            1,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_f64(f64::NAN).unwrap();
+        processor.push_f64(2.5).unwrap();
+        ge_f64(&mut processor).expect("[3] The ge must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_f32(f32::NAN).unwrap();
+        processor.push_f32(f32::NAN).unwrap();
+        ne_f32(&mut processor).expect("[4] The ne must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            1,
+            "[4] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_comparison_with_branch() {
+        let program = Program::new_for_tests(vec![0; 10], 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        processor.push_u32(7).unwrap();
+        processor.push_u16(3).unwrap();
+        processor.push_u16(4).unwrap();
+        lt_16(&mut processor).expect("The lt must succeed");
+        branch_if_8(&mut processor).expect("The branch must succeed");
+        assert_eq!(
+            processor.program_counter(),
+            7,
+            "The program counter is incorrect"
+        );
+    }
+}
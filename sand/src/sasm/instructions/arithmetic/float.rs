@@ -0,0 +1,826 @@
+use crate::sasm::{Action, Processor};
+
+/// Pops two f32 values from the stack and pushes their sum.
+/// If any operand is NaN the result is NaN. The sum of infinities with different signs is NaN.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn add_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = left + right;
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes their sum.
+/// If any operand is NaN the result is NaN. The sum of infinities with different signs is NaN.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn add_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = left + right;
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes their difference: left - right.
+/// If any operand is NaN the result is NaN. The difference of infinities with the same sign is NaN.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn sub_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = left - right;
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes their difference: left - right.
+/// If any operand is NaN the result is NaN. The difference of infinities with the same sign is NaN.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn sub_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = left - right;
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes their product.
+/// If any operand is NaN the result is NaN. The product of zero and infinity is NaN.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn mul_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = left * right;
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes their product.
+/// If any operand is NaN the result is NaN. The product of zero and infinity is NaN.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn mul_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = left * right;
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes their quotient: left / right.
+/// If any operand is NaN the result is NaN.
+/// Zero divided by zero and infinity divided by infinity are NaN. Dividing any other value
+/// by zero results in an infinity.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn div_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = left / right;
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes their quotient: left / right.
+/// If any operand is NaN the result is NaN.
+/// Zero divided by zero and infinity divided by infinity are NaN. Dividing any other value
+/// by zero results in an infinity.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn div_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = left / right;
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes it with its sign inverted.
+/// NaN values are pushed as NaN with their sign bit inverted.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn neg_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = -input_value;
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes it with its sign inverted.
+/// NaN values are pushed as NaN with their sign bit inverted.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn neg_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = -input_value;
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes its absolute value.
+/// NaN values are pushed as NaN with their sign bit cleared.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn abs_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.abs();
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes its absolute value.
+/// NaN values are pushed as NaN with their sign bit cleared.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn abs_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.abs();
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes its square root.
+/// If the value is NaN or lower than zero the result is NaN. The square root of -0 is -0.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn sqrt_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.sqrt();
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes its square root.
+/// If the value is NaN or lower than zero the result is NaN. The square root of -0 is -0.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn sqrt_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.sqrt();
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes the lower one.
+/// If any operand is NaN the result is NaN. -0 is considered lower than +0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn min_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = if left.is_nan() || right.is_nan() {
+        f32::NAN
+    } else if left == right && left.is_sign_negative() {
+        left
+    } else if left == right {
+        right
+    } else {
+        left.min(right)
+    };
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes the lower one.
+/// If any operand is NaN the result is NaN. -0 is considered lower than +0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn min_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = if left.is_nan() || right.is_nan() {
+        f64::NAN
+    } else if left == right && left.is_sign_negative() {
+        left
+    } else if left == right {
+        right
+    } else {
+        left.min(right)
+    };
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes the greater one.
+/// If any operand is NaN the result is NaN. +0 is considered greater than -0.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn max_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = if left.is_nan() || right.is_nan() {
+        f32::NAN
+    } else if left == right && left.is_sign_positive() {
+        left
+    } else if left == right {
+        right
+    } else {
+        left.max(right)
+    };
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes the greater one.
+/// If any operand is NaN the result is NaN. +0 is considered greater than -0.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn max_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = if left.is_nan() || right.is_nan() {
+        f64::NAN
+    } else if left == right && left.is_sign_positive() {
+        left
+    } else if left == right {
+        right
+    } else {
+        left.max(right)
+    };
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes the greatest integer lower than or equal to it.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn floor_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.floor();
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes the greatest integer lower than or equal to it.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn floor_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.floor();
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes the lowest integer greater than or equal to it.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn ceil_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.ceil();
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes the lowest integer greater than or equal to it.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn ceil_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.ceil();
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes its integer part, rounding towards zero.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn trunc_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = input_value.trunc();
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes its integer part, rounding towards zero.
+/// NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn trunc_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = input_value.trunc();
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f32 value from the stack and pushes the nearest integer to it, rounding half-way
+/// cases to the even integer. NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f32 - Input value.
+/// + f32 - Output value.
+pub fn nearest_f32(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f32()?;
+    let output_value = if (input_value - input_value.trunc()).abs() == 0.5 {
+        2.0 * (input_value / 2.0).round()
+    } else {
+        input_value.round()
+    };
+
+    processor.push_f32(output_value)?;
+
+    Ok(())
+}
+
+/// Pops a f64 value from the stack and pushes the nearest integer to it, rounding half-way
+/// cases to the even integer. NaN, infinities and zeros are pushed unchanged.
+///
+/// Stack:
+/// - f64 - Input value.
+/// + f64 - Output value.
+pub fn nearest_f64(processor: &mut Processor) -> Result<(), Action> {
+    let input_value = processor.pop_f64()?;
+    let output_value = if (input_value - input_value.trunc()).abs() == 0.5 {
+        2.0 * (input_value / 2.0).round()
+    } else {
+        input_value.round()
+    };
+
+    processor.push_f64(output_value)?;
+
+    Ok(())
+}
+
+/// Pops two f32 values from the stack and pushes the left one with the sign of the right one.
+/// NaN values are treated as any other value: their sign bit is copied or replaced.
+///
+/// Stack:
+/// - f32 - Right operand.
+/// - f32 - Left operand.
+/// + f32 - Result.
+pub fn copysign_f32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f32()?;
+    let left = processor.pop_f32()?;
+    let result = left.copysign(right);
+
+    processor.push_f32(result)?;
+
+    Ok(())
+}
+
+/// Pops two f64 values from the stack and pushes the left one with the sign of the right one.
+/// NaN values are treated as any other value: their sign bit is copied or replaced.
+///
+/// Stack:
+/// - f64 - Right operand.
+/// - f64 - Left operand.
+/// + f64 - Result.
+pub fn copysign_f64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_f64()?;
+    let left = processor.pop_f64()?;
+    let result = left.copysign(right);
+
+    processor.push_f64(result)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_binary_f32() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_f32(1.5).unwrap();
+        processor.push_f32(2.25).unwrap();
+        add_f32(&mut processor).expect("[1] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            3.75,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_f32(1.5).unwrap();
+        processor.push_f32(2.25).unwrap();
+        sub_f32(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            -0.75,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_f32(1.5).unwrap();
+        processor.push_f32(-2.0).unwrap();
+        mul_f32(&mut processor).expect("[3] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            -3.0,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_f32(1.0).unwrap();
+        processor.push_f32(0.0).unwrap();
+        div_f32(&mut processor).expect("[4] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            f32::INFINITY,
+            "[4] The result is incorrect"
+        );
+
+        // Case 5
+        processor.push_f32(0.0).unwrap();
+        processor.push_f32(-0.0).unwrap();
+        min_f32(&mut processor).expect("[5] The method must succeed");
+        let result = processor.pop_f32().unwrap();
+        assert_eq!(result, -0.0, "[5] The result is incorrect");
+        assert!(result.is_sign_negative(), "[5] The sign is incorrect");
+
+        // Case 6
+        processor.push_f32(1.0).unwrap();
+        processor.push_f32(2.0).unwrap();
+        min_f32(&mut processor).expect("[6] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            1.0,
+            "[6] The result is incorrect"
+        );
+
+        // Case 7
+        processor.push_f32(-0.0).unwrap();
+        processor.push_f32(0.0).unwrap();
+        max_f32(&mut processor).expect("[7] The method must succeed");
+        let result = processor.pop_f32().unwrap();
+        assert_eq!(result, 0.0, "[7] The result is incorrect");
+        assert!(result.is_sign_positive(), "[7] The sign is incorrect");
+
+        // Case 8
+        processor.push_f32(1.0).unwrap();
+        processor.push_f32(2.0).unwrap();
+        max_f32(&mut processor).expect("[8] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            2.0,
+            "[8] The result is incorrect"
+        );
+
+        // Case 9
+        processor.push_f32(3.0).unwrap();
+        processor.push_f32(-0.0).unwrap();
+        copysign_f32(&mut processor).expect("[9] The method must succeed");
+        assert_eq!(
+            processor.pop_f32().unwrap(),
+            -3.0,
+            "[9] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_unary_f64() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_f64(1.5).unwrap();
+        neg_f64(&mut processor).expect("[1] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -1.5,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_f64(-1.5).unwrap();
+        abs_f64(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            1.5,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_f64(6.25).unwrap();
+        sqrt_f64(&mut processor).expect("[3] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            2.5,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_f64(-1.5).unwrap();
+        floor_f64(&mut processor).expect("[4] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -2.0,
+            "[4] The result is incorrect"
+        );
+
+        // Case 5
+        processor.push_f64(-1.5).unwrap();
+        ceil_f64(&mut processor).expect("[5] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -1.0,
+            "[5] The result is incorrect"
+        );
+
+        // Case 6
+        processor.push_f64(-1.5).unwrap();
+        trunc_f64(&mut processor).expect("[6] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -1.0,
+            "[6] The result is incorrect"
+        );
+
+        // Case 7
+        processor.push_f64(2.5).unwrap();
+        nearest_f64(&mut processor).expect("[7] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            2.0,
+            "[7] The result is incorrect"
+        );
+
+        // Case 8
+        processor.push_f64(3.5).unwrap();
+        nearest_f64(&mut processor).expect("[8] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            4.0,
+            "[8] The result is incorrect"
+        );
+
+        // Case 9
+        processor.push_f64(-2.5).unwrap();
+        nearest_f64(&mut processor).expect("[9] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            -2.0,
+            "[9] The result is incorrect"
+        );
+
+        // Case 10
+        processor.push_f64(2.4).unwrap();
+        nearest_f64(&mut processor).expect("[10] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            2.0,
+            "[10] The result is incorrect"
+        );
+
+        // Case 11
+        processor.push_f64(2.6).unwrap();
+        nearest_f64(&mut processor).expect("[11] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            3.0,
+            "[11] The result is incorrect"
+        );
+
+        // Case 12
+        processor.push_f64(f64::INFINITY).unwrap();
+        nearest_f64(&mut processor).expect("[12] The method must succeed");
+        assert_eq!(
+            processor.pop_f64().unwrap(),
+            f64::INFINITY,
+            "[12] The result is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_nan() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        add_f64(&mut processor).expect("[1] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[1] The result must be NaN"
+        );
+
+        // Case 2
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        sub_f64(&mut processor).expect("[2] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[2] The result must be NaN"
+        );
+
+        // Case 3
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        mul_f64(&mut processor).expect("[3] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[3] The result must be NaN"
+        );
+
+        // Case 4
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        div_f64(&mut processor).expect("[4] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[4] The result must be NaN"
+        );
+
+        // Case 5
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        min_f64(&mut processor).expect("[5] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[5] The result must be NaN"
+        );
+
+        // Case 6
+        processor.push_f64(1.0).unwrap();
+        processor.push_f64(f64::NAN).unwrap();
+        max_f64(&mut processor).expect("[6] The method must succeed");
+        assert!(
+            processor.pop_f64().unwrap().is_nan(),
+            "[6] The result must be NaN"
+        );
+
+        // Case 7
+        processor.push_f32(f32::NAN).unwrap();
+        sqrt_f32(&mut processor).expect("[7] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[7] The result must be NaN"
+        );
+
+        // Case 8
+        processor.push_f32(f32::NAN).unwrap();
+        floor_f32(&mut processor).expect("[8] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[8] The result must be NaN"
+        );
+
+        // Case 9
+        processor.push_f32(f32::NAN).unwrap();
+        ceil_f32(&mut processor).expect("[9] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[9] The result must be NaN"
+        );
+
+        // Case 10
+        processor.push_f32(f32::NAN).unwrap();
+        trunc_f32(&mut processor).expect("[10] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[10] The result must be NaN"
+        );
+
+        // Case 11
+        processor.push_f32(f32::NAN).unwrap();
+        nearest_f32(&mut processor).expect("[11] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[11] The result must be NaN"
+        );
+
+        // Case 12
+        processor.push_f32(f32::NAN).unwrap();
+        abs_f32(&mut processor).expect("[12] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[12] The result must be NaN"
+        );
+
+        // Case 13
+        processor.push_f32(-1.0).unwrap();
+        sqrt_f32(&mut processor).expect("[13] The method must succeed");
+        assert!(
+            processor.pop_f32().unwrap().is_nan(),
+            "[13] The result must be NaN"
+        );
+    }
+}
@@ -0,0 +1,1047 @@
+use crate::sasm::{Action, Processor, TrapKind};
+
+/// Pops two ?8 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the unsigned addition overflows.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn add_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the unsigned addition overflows.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn add_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the unsigned addition overflows.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn add_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the unsigned addition overflows.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn add_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?8 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the unsigned subtraction overflows.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn sub_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the unsigned subtraction overflows.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn sub_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the unsigned subtraction overflows.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn sub_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the unsigned subtraction overflows.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn sub_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?8 values from the stack and pushes their product.
+/// Sets the overflow_flag when the unsigned multiplication overflows.
+///
+/// Stack:
+/// - ?8 - Right operand.
+/// - ?8 - Left operand.
+/// + ?8 - Result.
+pub fn mul_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?16 values from the stack and pushes their product.
+/// Sets the overflow_flag when the unsigned multiplication overflows.
+///
+/// Stack:
+/// - ?16 - Right operand.
+/// - ?16 - Left operand.
+/// + ?16 - Result.
+pub fn mul_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?32 values from the stack and pushes their product.
+/// Sets the overflow_flag when the unsigned multiplication overflows.
+///
+/// Stack:
+/// - ?32 - Right operand.
+/// - ?32 - Left operand.
+/// + ?32 - Result.
+pub fn mul_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two ?64 values from the stack and pushes their product.
+/// Sets the overflow_flag when the unsigned multiplication overflows.
+///
+/// Stack:
+/// - ?64 - Right operand.
+/// - ?64 - Left operand.
+/// + ?64 - Result.
+pub fn mul_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the signed addition overflows.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + i8 - Result.
+pub fn add_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the signed addition overflows.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + i16 - Result.
+pub fn add_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the signed addition overflows.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + i32 - Result.
+pub fn add_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and pushes their sum.
+/// Sets the overflow_flag when the signed addition overflows.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + i64 - Result.
+pub fn add_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let (result, overflow) = left.overflowing_add(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the signed subtraction overflows.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + i8 - Result.
+pub fn sub_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the signed subtraction overflows.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + i16 - Result.
+pub fn sub_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the signed subtraction overflows.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + i32 - Result.
+pub fn sub_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and pushes their difference: left - right.
+/// Sets the overflow_flag when the signed subtraction overflows.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + i64 - Result.
+pub fn sub_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let (result, overflow) = left.overflowing_sub(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and pushes their product.
+/// Sets the overflow_flag when the signed multiplication overflows.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + i8 - Result.
+pub fn mul_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and pushes their product.
+/// Sets the overflow_flag when the signed multiplication overflows.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + i16 - Result.
+pub fn mul_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and pushes their product.
+/// Sets the overflow_flag when the signed multiplication overflows.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + i32 - Result.
+pub fn mul_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and pushes their product.
+/// Sets the overflow_flag when the signed multiplication overflows.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + i64 - Result.
+pub fn mul_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+    let (result, overflow) = left.overflowing_mul(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and pushes their quotient: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - Result.
+pub fn div_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left / right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and pushes their quotient: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u16 - Result.
+pub fn div_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left / right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and pushes their quotient: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u32 - Result.
+pub fn div_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left / right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and pushes their quotient: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u64 - Result.
+pub fn div_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left / right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and pushes their quotient: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + i8 - Result.
+pub fn div_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_div(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and pushes their quotient: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + i16 - Result.
+pub fn div_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_div(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and pushes their quotient: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + i32 - Result.
+pub fn div_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_div(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and pushes their quotient: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + i64 - Result.
+pub fn div_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_div(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+/// Pops two u8 values from the stack and pushes the remainder of: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u8 - Right operand.
+/// - u8 - Left operand.
+/// + u8 - Result.
+pub fn rem_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u8()?;
+    let left = processor.pop_u8()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left % right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u8(result)?;
+
+    Ok(())
+}
+
+/// Pops two u16 values from the stack and pushes the remainder of: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u16 - Right operand.
+/// - u16 - Left operand.
+/// + u16 - Result.
+pub fn rem_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u16()?;
+    let left = processor.pop_u16()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left % right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u16(result)?;
+
+    Ok(())
+}
+
+/// Pops two u32 values from the stack and pushes the remainder of: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u32 - Right operand.
+/// - u32 - Left operand.
+/// + u32 - Result.
+pub fn rem_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u32()?;
+    let left = processor.pop_u32()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left % right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u32(result)?;
+
+    Ok(())
+}
+
+/// Pops two u64 values from the stack and pushes the remainder of: left / right.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - u64 - Right operand.
+/// - u64 - Left operand.
+/// + u64 - Result.
+pub fn rem_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_u64()?;
+    let left = processor.pop_u64()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let result = left % right;
+
+    processor.set_overflow_flag(false);
+    processor.push_u64(result)?;
+
+    Ok(())
+}
+
+/// Pops two i8 values from the stack and pushes the remainder of: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i8 - Right operand.
+/// - i8 - Left operand.
+/// + i8 - Result.
+pub fn rem_sign_8(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i8()?;
+    let left = processor.pop_i8()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_rem(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i8(result)?;
+
+    Ok(())
+}
+
+/// Pops two i16 values from the stack and pushes the remainder of: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i16 - Right operand.
+/// - i16 - Left operand.
+/// + i16 - Result.
+pub fn rem_sign_16(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i16()?;
+    let left = processor.pop_i16()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_rem(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i16(result)?;
+
+    Ok(())
+}
+
+/// Pops two i32 values from the stack and pushes the remainder of: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i32 - Right operand.
+/// - i32 - Left operand.
+/// + i32 - Result.
+pub fn rem_sign_32(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i32()?;
+    let left = processor.pop_i32()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_rem(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i32(result)?;
+
+    Ok(())
+}
+
+/// Pops two i64 values from the stack and pushes the remainder of: left / right.
+/// Sets the overflow_flag when the result overflows, i.e. the minimum value divided by -1.
+/// Can cause a trap when the right operand is zero.
+///
+/// Stack:
+/// - i64 - Right operand.
+/// - i64 - Left operand.
+/// + i64 - Result.
+pub fn rem_sign_64(processor: &mut Processor) -> Result<(), Action> {
+    let right = processor.pop_i64()?;
+    let left = processor.pop_i64()?;
+
+    if right == 0 {
+        return Err(Action::trap(TrapKind::DivisionByZero));
+    }
+
+    let (result, overflow) = left.overflowing_rem(right);
+
+    processor.set_overflow_flag(overflow);
+    processor.push_i64(result)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_add_sub_mul() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_u8(0x12).unwrap();
+        processor.push_u8(0x34).unwrap();
+        add_8(&mut processor).expect("[1] The add must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x46,
+            "[1] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[1] The overflow flag is incorrect"
+        );
+
+        // Case 2
+        processor.push_u16(0xffff).unwrap();
+        processor.push_u16(0x0002).unwrap();
+        add_16(&mut processor).expect("[2] The add must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x0001,
+            "[2] The result is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[2] The overflow flag is incorrect"
+        );
+
+        // Case 3
+        processor.push_u32(5).unwrap();
+        processor.push_u32(7).unwrap();
+        sub_32(&mut processor).expect("[3] The sub must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            -2,
+            "[3] The result is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[3] The overflow flag is incorrect"
+        );
+
+        // Case 4
+        processor.push_u64(0x1_0000_0000).unwrap();
+        processor.push_u64(0x3).unwrap();
+        mul_64(&mut processor).expect("[4] The mul must succeed");
+        assert_eq!(
+            processor.pop_u64().unwrap(),
+            0x3_0000_0000,
+            "[4] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[4] The overflow flag is incorrect"
+        );
+
+        // Case 5: a signed overflow is not an unsigned one.
+        processor.push_u8(0x7f).unwrap();
+        processor.push_u8(0x01).unwrap();
+        add_8(&mut processor).expect("[5] The add must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x80,
+            "[5] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[5] The overflow flag is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_add_sub_mul_sign() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_i8(0x7f).unwrap();
+        processor.push_i8(0x01).unwrap();
+        add_sign_8(&mut processor).expect("[1] The add must succeed");
+        assert_eq!(
+            processor.pop_i8().unwrap(),
+            -0x80,
+            "[1] The result is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[1] The overflow flag is incorrect"
+        );
+
+        // Case 2
+        processor.push_i16(-1).unwrap();
+        processor.push_i16(1).unwrap();
+        add_sign_16(&mut processor).expect("[2] The add must succeed");
+        assert_eq!(
+            processor.pop_i16().unwrap(),
+            0,
+            "[2] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[2] The overflow flag is incorrect"
+        );
+
+        // Case 3
+        processor.push_i32(5).unwrap();
+        processor.push_i32(7).unwrap();
+        sub_sign_32(&mut processor).expect("[3] The sub must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            -2,
+            "[3] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[3] The overflow flag is incorrect"
+        );
+
+        // Case 4
+        processor.push_i64(i64::MIN).unwrap();
+        processor.push_i64(1).unwrap();
+        sub_sign_64(&mut processor).expect("[4] The sub must succeed");
+        assert_eq!(
+            processor.pop_i64().unwrap(),
+            i64::MAX,
+            "[4] The result is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[4] The overflow flag is incorrect"
+        );
+
+        // Case 5
+        processor.push_i32(-2).unwrap();
+        processor.push_i32(3).unwrap();
+        mul_sign_32(&mut processor).expect("[5] The mul must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            -6,
+            "[5] The result is incorrect"
+        );
+        assert!(
+            !processor.overflow_flag(),
+            "[5] The overflow flag is incorrect"
+        );
+
+        // Case 6
+        processor.push_i64(0x4000_0000_0000_0000).unwrap();
+        processor.push_i64(2).unwrap();
+        mul_sign_64(&mut processor).expect("[6] The mul must succeed");
+        assert!(
+            processor.overflow_flag(),
+            "[6] The overflow flag is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 40);
+
+        // Case 1
+        processor.push_u8(0xfe).unwrap();
+        processor.push_u8(0x02).unwrap();
+        div_8(&mut processor).expect("[1] The div must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x7f,
+            "[1] The result is incorrect"
+        );
+
+        // Case 2
+        processor.push_i8(-7).unwrap();
+        processor.push_i8(2).unwrap();
+        div_sign_8(&mut processor).expect("[2] The div must succeed");
+        assert_eq!(
+            processor.pop_i8().unwrap(),
+            -3,
+            "[2] The result is incorrect"
+        );
+
+        // Case 3
+        processor.push_i32(-7).unwrap();
+        processor.push_i32(2).unwrap();
+        rem_sign_32(&mut processor).expect("[3] The rem must succeed");
+        assert_eq!(
+            processor.pop_i32().unwrap(),
+            -1,
+            "[3] The result is incorrect"
+        );
+
+        // Case 4
+        processor.push_u16(17).unwrap();
+        processor.push_u16(5).unwrap();
+        rem_16(&mut processor).expect("[4] The rem must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            2,
+            "[4] The result is incorrect"
+        );
+
+        // Case 5: overflow
+        processor.push_i64(i64::MIN).unwrap();
+        processor.push_i64(-1).unwrap();
+        div_sign_64(&mut processor).expect("[5] The div must succeed");
+        assert_eq!(
+            processor.pop_i64().unwrap(),
+            i64::MIN,
+            "[5] The result is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[5] The overflow flag is incorrect"
+        );
+
+        // Case 6: division by zero
+        processor.push_u32(1).unwrap();
+        processor.push_u32(0).unwrap();
+        let result = div_32(&mut processor).expect_err("[6] The div must fail");
+        assert_eq!(result.unwrap_trap().kind, TrapKind::DivisionByZero);
+    }
+}
@@ -1,3 +1,11 @@
+pub use bitwise::*;
 pub use casting::*;
+pub use comparison::*;
+pub use float::*;
+pub use integer::*;
 
+mod bitwise;
 mod casting;
+mod comparison;
+mod float;
+mod integer;
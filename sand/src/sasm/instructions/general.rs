@@ -1,8 +1,9 @@
-use crate::sasm::{Action, Processor};
+use crate::sasm::instructions::{EXTENDED_INSTRUCTION_LIST, INSTRUCTION_LIST};
+use crate::sasm::{Action, Processor, TrapKind};
 
-/// Throws a panic signal to the processor finishing the execution.
+/// Raises an unreachable trap finishing the execution.
 pub fn unreachable(_: &mut Processor) -> Result<(), Action> {
-    Err(Action::Panic("unreachable"))
+    Err(Action::trap(TrapKind::Unreachable))
 }
 
 /// Does nothing.
@@ -16,7 +17,7 @@ pub fn debug(_: &mut Processor) -> Result<(), Action> {
 }
 
 /// Pops a ?32 value from the stack and jumps to the code position it points to.
-/// Can cause a panic when the code position is unavailable.
+/// Can cause a trap when the code position is unavailable.
 ///
 /// Stack:
 /// - u32 - Code position.
@@ -30,7 +31,7 @@ pub fn branch(processor: &mut Processor) -> Result<(), Action> {
 
 /// Pops ?8 value from the stack that acts as a condition for branching
 /// to the next ?32 value and jumps to the code position it points to.
-/// Can cause a panic when the code position is unavailable.
+/// Can cause a trap when the code position is unavailable.
 ///
 /// Stack:
 /// - u8 - Condition.
@@ -48,7 +49,7 @@ pub fn branch_if_8(processor: &mut Processor) -> Result<(), Action> {
 
 /// Pops ?16 value from the stack that acts as a condition for branching
 /// to the next ?32 value and jumps to the code position it points to.
-/// Can cause a panic when the code position is unavailable.
+/// Can cause a trap when the code position is unavailable.
 ///
 /// Stack:
 /// - u16 - Condition.
@@ -66,7 +67,7 @@ pub fn branch_if_16(processor: &mut Processor) -> Result<(), Action> {
 
 /// Pops ?32 value from the stack that acts as a condition for branching
 /// to the next ?32 value and jumps to the code position it points to.
-/// Can cause a panic when the code position is unavailable.
+/// Can cause a trap when the code position is unavailable.
 ///
 /// Stack:
 /// - u32 - Condition.
@@ -84,7 +85,7 @@ pub fn branch_if_32(processor: &mut Processor) -> Result<(), Action> {
 
 /// Pops ?64 value from the stack that acts as a condition for branching
 /// to the next ?32 value and jumps to the code position it points to.
-/// Can cause a panic when the code position is unavailable.
+/// Can cause a trap when the code position is unavailable.
 ///
 /// Stack:
 /// - u64 - Condition.
@@ -99,3 +100,189 @@ pub fn branch_if_64(processor: &mut Processor) -> Result<(), Action> {
 
     Ok(())
 }
+
+/// Pops a ?32 value from the stack and calls the subroutine at the code position it points to.
+/// The execution continues at the next instruction when the subroutine returns.
+/// Can cause a trap when the code position is unavailable or the maximum call depth is reached.
+///
+/// Stack:
+/// - u32 - Code position.
+pub fn call(processor: &mut Processor) -> Result<(), Action> {
+    let code_position = processor.pop_u32()? as usize;
+
+    processor.call(code_position)?;
+
+    Ok(())
+}
+
+/// Returns from the current subroutine to the instruction that follows its call.
+/// Can cause a trap when no subroutine is being executed or when the subroutine has removed
+/// values that were in the stack before its call.
+pub fn r#return(processor: &mut Processor) -> Result<(), Action> {
+    processor.return_from_call()?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and jumps that number of bytes from the end of this
+/// instruction.
+/// Can cause a trap when the code position is unavailable.
+pub fn branch_rel(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let code_position = processor.relative_code_position(offset);
+
+    processor.set_program_counter(code_position)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and pops a ?8 value from the stack that acts as
+/// a condition for jumping that number of bytes from the end of this instruction.
+/// Can cause a trap when the code position is unavailable.
+///
+/// Stack:
+/// - u8 - Condition.
+pub fn branch_rel_if_8(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let condition = processor.pop_u8()?;
+
+    if condition != 0 {
+        let code_position = processor.relative_code_position(offset);
+        processor.set_program_counter(code_position)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and pops a ?16 value from the stack that acts as
+/// a condition for jumping that number of bytes from the end of this instruction.
+/// Can cause a trap when the code position is unavailable.
+///
+/// Stack:
+/// - u16 - Condition.
+pub fn branch_rel_if_16(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let condition = processor.pop_u16()?;
+
+    if condition != 0 {
+        let code_position = processor.relative_code_position(offset);
+        processor.set_program_counter(code_position)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and pops a ?32 value from the stack that acts as
+/// a condition for jumping that number of bytes from the end of this instruction.
+/// Can cause a trap when the code position is unavailable.
+///
+/// Stack:
+/// - u32 - Condition.
+pub fn branch_rel_if_32(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let condition = processor.pop_u32()?;
+
+    if condition != 0 {
+        let code_position = processor.relative_code_position(offset);
+        processor.set_program_counter(code_position)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and pops a ?64 value from the stack that acts as
+/// a condition for jumping that number of bytes from the end of this instruction.
+/// Can cause a trap when the code position is unavailable.
+///
+/// Stack:
+/// - u64 - Condition.
+pub fn branch_rel_if_64(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let condition = processor.pop_u64()?;
+
+    if condition != 0 {
+        let code_position = processor.relative_code_position(offset);
+        processor.set_program_counter(code_position)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code and calls the subroutine placed that number of bytes
+/// from the end of this instruction.
+/// The execution continues at the next instruction when the subroutine returns.
+/// Can cause a trap when the code position is unavailable or the maximum call depth is reached.
+pub fn call_rel(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let code_position = processor.relative_code_position(offset);
+
+    processor.call(code_position)?;
+
+    Ok(())
+}
+
+/// Reads the next u32 value from the code and calls the host function registered at that index.
+/// The stack effect depends on the host function.
+/// Can cause a trap when no host function is registered at that index.
+pub fn call_host(processor: &mut Processor) -> Result<(), Action> {
+    let index = processor.code_next_u32()?;
+
+    processor.call_host(index)?;
+
+    Ok(())
+}
+
+/// Reads the next u32 value from the code as the number of entries of the table of i32 values
+/// that follows it, which ends with an extra default entry. Then pops a ?32 value as an index
+/// of the table and jumps the number of bytes of that entry from the end of this instruction,
+/// or the number of bytes of the default entry if the index is out of the table.
+/// Can cause a trap when the code position is unavailable.
+///
+/// Stack:
+/// - u32 - Index.
+pub fn branch_table(processor: &mut Processor) -> Result<(), Action> {
+    let count = processor.code_next_u32()? as usize;
+    let index = processor.pop_u32()? as usize;
+
+    let entry_size = std::mem::size_of::<i32>();
+    let table_position = processor.program_counter();
+    let end_position = table_position + (count + 1) * entry_size;
+    let entry_position = table_position + index.min(count) * entry_size;
+    let offset = processor.program().read_i32_at(entry_position)?;
+
+    let code_position = end_position.wrapping_add(offset as isize as usize);
+
+    processor.set_program_counter(code_position)?;
+
+    Ok(())
+}
+
+/// Raises an invalid opcode trap for the opcode of the base set that has just been read.
+/// Fills the slots of the unassigned opcodes.
+pub fn invalid_opcode(processor: &mut Processor) -> Result<(), Action> {
+    let opcode = processor
+        .program()
+        .read_u8_at(processor.program_counter() - 1)?;
+
+    Err(Action::trap(TrapKind::InvalidOpcode(opcode as usize)))
+}
+
+/// Raises an invalid opcode trap for the opcode of the extended set that has just been read.
+/// Fills the slots of the unassigned extended opcodes.
+pub fn invalid_extended_opcode(processor: &mut Processor) -> Result<(), Action> {
+    let opcode = processor
+        .program()
+        .read_u8_at(processor.program_counter() - 1)?;
+
+    Err(Action::trap(TrapKind::InvalidOpcode(
+        INSTRUCTION_LIST.len() + opcode as usize,
+    )))
+}
+
+/// Reads the next u8 value from the code and executes the instruction of the extended set
+/// it points to.
+pub fn extended(processor: &mut Processor) -> Result<(), Action> {
+    let opcode = processor.code_next_u8()?;
+
+    EXTENDED_INSTRUCTION_LIST[opcode as usize](processor)
+}
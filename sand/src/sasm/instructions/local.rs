@@ -0,0 +1,225 @@
+use crate::sasm::{Action, Processor};
+
+/// Reads the next i32 value from the code as an offset from the frame pointer, loads the ?8
+/// value of the stack placed there and pushes it into the stack.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// + ?8  - Local value.
+pub fn local_get_8(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u8>())?;
+
+    let value = processor.memory().read_u8_at(position)?;
+    processor.push_u8(value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer, loads the ?16
+/// value of the stack placed there and pushes it into the stack.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// + ?16 - Local value.
+pub fn local_get_16(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u16>())?;
+
+    let value = processor.memory().read_u16_at(position)?;
+    processor.push_u16(value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer, loads the ?32
+/// value of the stack placed there and pushes it into the stack.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// + ?32 - Local value.
+pub fn local_get_32(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u32>())?;
+
+    let value = processor.memory().read_u32_at(position)?;
+    processor.push_u32(value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer, loads the ?64
+/// value of the stack placed there and pushes it into the stack.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// + ?64 - Local value.
+pub fn local_get_64(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u64>())?;
+
+    let value = processor.memory().read_u64_at(position)?;
+    processor.push_u64(value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer and pops a ?8
+/// value from the stack to store it in the stack position placed there.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// - ?8  - Local value.
+pub fn local_set_8(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let value = processor.pop_u8()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u8>())?;
+
+    processor.memory_mut().write_u8_at(position, value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer and pops a ?16
+/// value from the stack to store it in the stack position placed there.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// - ?16 - Local value.
+pub fn local_set_16(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let value = processor.pop_u16()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u16>())?;
+
+    processor.memory_mut().write_u16_at(position, value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer and pops a ?32
+/// value from the stack to store it in the stack position placed there.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// - ?32 - Local value.
+pub fn local_set_32(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let value = processor.pop_u32()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u32>())?;
+
+    processor.memory_mut().write_u32_at(position, value)?;
+
+    Ok(())
+}
+
+/// Reads the next i32 value from the code as an offset from the frame pointer and pops a ?64
+/// value from the stack to store it in the stack position placed there.
+/// Can cause a trap when the position is outside the used part of the stack.
+///
+/// Stack:
+/// - ?64 - Local value.
+pub fn local_set_64(processor: &mut Processor) -> Result<(), Action> {
+    let offset = processor.code_next_i32()?;
+    let value = processor.pop_u64()?;
+    let position = processor.local_position(offset, std::mem::size_of::<u64>())?;
+
+    processor.memory_mut().write_u64_at(position, value)?;
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::instructions::Instruction;
+    use crate::sasm::{Program, TrapKind};
+
+    use super::*;
+
+    #[test]
+    fn test_locals() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const32 as u8,
+                0x44,
+                0x33,
+                0x22,
+                0x11,
+                Instruction::Const32 as u8,
+                0x0c,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Call as u8,
+                Instruction::Debug as u8,
+                // Subroutine.
+                Instruction::Const32 as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::LocalGet32 as u8,
+                0xfc,
+                0xff,
+                0xff,
+                0xff,
+                Instruction::LocalSet32 as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Return as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.run_for(5).expect("[1] The run must succeed");
+        assert_eq!(
+            processor.frame_pointer(),
+            4,
+            "[1] The frame pointer is incorrect"
+        );
+        assert_eq!(
+            processor.peek_u32().unwrap(),
+            0x11223344,
+            "[1] The local value is incorrect"
+        );
+
+        // Case 2
+        let result = processor.run();
+        assert!(result.is_halt(), "[2] The processor must halt");
+        assert_eq!(
+            processor.frame_pointer(),
+            0,
+            "[2] The frame pointer is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u64().unwrap(),
+            0x11223344_11223344,
+            "[2] The values stored in the stack are incorrect"
+        );
+    }
+
+    #[test]
+    fn test_locals_out_of_stack() {
+        let program = Program::new_for_tests(
+            vec![Instruction::LocalGet8 as u8, 0x00, 0x00, 0x00, 0x00],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        let result = processor.run();
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::LocalOutOfBounds { offset: 0, size: 1 },
+            "The error is incorrect"
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use crate::sasm::{Action, Processor};
+use crate::sasm::{Action, Processor, TrapKind};
+
+/// The maximum memory size the instructions can work with, because both the sizes and
+/// the addresses are u32.
+const MEMORY_MAX_ADDRESSABLE_SIZE: usize = u32::MAX as usize;
 
 /// Push the current memory size in bytes to the stack.
+/// Memories that the host grows beyond the addressable size report `u32::MAX`.
 ///
 /// Stack:
 /// + u32 - Memory size
 pub fn memory_size(processor: &mut Processor) -> Result<(), Action> {
-    let memory_size = processor.memory().size() as u32;
+    let memory_size = addressable_memory_size(processor);
     processor.push_u32(memory_size)?;
     Ok(())
 }
@@ -16,28 +21,38 @@ pub fn memory_size(processor: &mut Processor) -> Result<(), Action> {
 /// - u32 - Number of bytes.
 /// + u32 - Previous size.
 ///
-/// If it fails, the overflow_flag is set.
+/// If it fails, the overflow_flag is set. This includes the case where the new size
+/// would not fit in a u32.
 pub fn memory_grow(processor: &mut Processor) -> Result<(), Action> {
-    let number_of_bytes = processor.pop_u32()?;
-    let memory_size = processor.memory().size() as u32;
-    let page_size = processor.memory().page_size() as u32;
+    let number_of_bytes = processor.pop_u32()? as usize;
+    let memory_size = addressable_memory_size(processor);
+    let page_size = processor.memory().page_size();
     let mut pages = number_of_bytes / page_size;
     if number_of_bytes % page_size != 0 {
         pages += 1;
     }
 
-    let is_error = processor
-        .memory_mut()
-        .add_empty_pages(pages as usize)
-        .is_err();
+    let new_size = pages
+        .checked_mul(page_size)
+        .and_then(|v| v.checked_add(processor.memory().size()));
+    let is_error = match new_size {
+        Some(v) if v <= MEMORY_MAX_ADDRESSABLE_SIZE => {
+            processor.memory_mut().add_empty_pages(pages).is_err()
+        }
+        _ => true,
+    };
     processor.set_overflow_flag(is_error);
     processor.push_u32(memory_size)?;
 
     Ok(())
 }
 
+fn addressable_memory_size(processor: &Processor) -> u32 {
+    processor.memory().size().min(MEMORY_MAX_ADDRESSABLE_SIZE) as u32
+}
+
 /// Fills a region of the memory with the specified value of ?8.
-/// Can cause a panic when the memory region is unavailable.
+/// Can cause a trap when the memory region is unavailable.
 ///
 /// Stack:
 /// - u8  - The value to use to fill the memory.
@@ -58,7 +73,7 @@ pub fn memory_fill_8(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Fills a region of the memory with the specified value of ?16.
-/// Can cause a panic when the memory region is unavailable.
+/// Can cause a trap when the memory region is unavailable.
 ///
 /// Stack:
 /// - u16 - The value to use to fill the memory.
@@ -80,7 +95,7 @@ pub fn memory_fill_16(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Fills a region of the memory with the specified value of ?32.
-/// Can cause a panic when the memory region is unavailable.
+/// Can cause a trap when the memory region is unavailable.
 ///
 /// Stack:
 /// - u32 - The value to use to fill the memory.
@@ -102,7 +117,7 @@ pub fn memory_fill_32(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Fills a region of the memory with the specified value of ?64.
-/// Can cause a panic when the memory region is unavailable.
+/// Can cause a trap when the memory region is unavailable.
 ///
 /// Stack:
 /// - u64 - The value to use to fill the memory.
@@ -124,7 +139,7 @@ pub fn memory_fill_64(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Copies a region of memory into another one.
-/// Can cause a panic when either the origin or target memory regions are unavailable.
+/// Can cause a trap when either the origin or target memory regions are unavailable.
 ///
 /// Stack:
 /// - u32 - Target pointer.
@@ -157,7 +172,7 @@ pub fn memory_copy(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?8 memory value and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -173,7 +188,7 @@ pub fn memory_load_8(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?16 memory value and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -189,7 +204,7 @@ pub fn memory_load_16(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?32 memory value and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -205,7 +220,7 @@ pub fn memory_load_32(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?64 memory value and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -221,7 +236,7 @@ pub fn memory_load_64(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Pops a ?8 value from the stack and stores it in memory.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - ?8  - Value.
@@ -237,7 +252,7 @@ pub fn memory_store_8(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Pops a ?16 value from the stack and stores it in memory.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - ?16  - Value.
@@ -253,7 +268,7 @@ pub fn memory_store_16(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Pops a ?32 value from the stack and stores it in memory.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - ?32  - Value.
@@ -269,7 +284,7 @@ pub fn memory_store_32(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Pops a ?64 value from the stack and stores it in memory.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - ?64  - Value.
@@ -285,7 +300,7 @@ pub fn memory_store_64(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?8 value from the program data and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -297,7 +312,10 @@ pub fn program_data_load_8(processor: &mut Processor) -> Result<(), Action> {
 
     let last_position = memory_position + std::mem::size_of::<u8>();
     if memory_position < program.data_pointer() || last_position > program.data_pointer_end() {
-        return Err(Action::Panic("Data Segmentation Fault"));
+        return Err(Action::trap(TrapKind::DataOutOfBounds {
+            address: memory_position,
+            size: std::mem::size_of::<u8>(),
+        }));
     }
 
     let value = program.read_u8_at(memory_position)?;
@@ -307,7 +325,7 @@ pub fn program_data_load_8(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?16 value from the program data and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -319,7 +337,10 @@ pub fn program_data_load_16(processor: &mut Processor) -> Result<(), Action> {
 
     let last_position = memory_position + std::mem::size_of::<u16>();
     if memory_position < program.data_pointer() || last_position > program.data_pointer_end() {
-        return Err(Action::Panic("Data Segmentation Fault"));
+        return Err(Action::trap(TrapKind::DataOutOfBounds {
+            address: memory_position,
+            size: std::mem::size_of::<u16>(),
+        }));
     }
 
     let value = program.read_u16_at(memory_position)?;
@@ -329,7 +350,7 @@ pub fn program_data_load_16(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?32 value from the program data and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -341,7 +362,10 @@ pub fn program_data_load_32(processor: &mut Processor) -> Result<(), Action> {
 
     let last_position = memory_position + std::mem::size_of::<u32>();
     if memory_position < program.data_pointer() || last_position > program.data_pointer_end() {
-        return Err(Action::Panic("Data Segmentation Fault"));
+        return Err(Action::trap(TrapKind::DataOutOfBounds {
+            address: memory_position,
+            size: std::mem::size_of::<u32>(),
+        }));
     }
 
     let value = program.read_u32_at(memory_position)?;
@@ -351,7 +375,7 @@ pub fn program_data_load_32(processor: &mut Processor) -> Result<(), Action> {
 }
 
 /// Loads a ?64 value from the program data and pushes it into the stack.
-/// Can cause a panic when the memory position is unavailable.
+/// Can cause a trap when the memory position is unavailable.
 ///
 /// Stack:
 /// - u32 - Memory position.
@@ -363,7 +387,10 @@ pub fn program_data_load_64(processor: &mut Processor) -> Result<(), Action> {
 
     let last_position = memory_position + std::mem::size_of::<u64>();
     if memory_position < program.data_pointer() || last_position > program.data_pointer_end() {
-        return Err(Action::Panic("Data Segmentation Fault"));
+        return Err(Action::trap(TrapKind::DataOutOfBounds {
+            address: memory_position,
+            size: std::mem::size_of::<u64>(),
+        }));
     }
 
     let value = program.read_u64_at(memory_position)?;
@@ -430,6 +457,59 @@ mod test {
             4 * MEMORY_DEFAULT_PAGE_SIZE,
             "[1] The current memory size is incorrect"
         );
+        assert!(
+            !processor.overflow_flag(),
+            "[1] The overflow flag must be clear"
+        );
+
+        // Case 2: grow past 4GiB.
+        processor.push_u32(u32::MAX).unwrap();
+        memory_grow(&mut processor).expect("[2] The method must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap() as usize,
+            4 * MEMORY_DEFAULT_PAGE_SIZE,
+            "[2] The old memory size is incorrect"
+        );
+        assert!(
+            processor.overflow_flag(),
+            "[2] The overflow flag must be set"
+        );
+        assert_eq!(
+            processor.memory().size(),
+            4 * MEMORY_DEFAULT_PAGE_SIZE,
+            "[2] The current memory size is incorrect"
+        );
+
+        // Case 3: grow up to the last addressable page.
+        let last_size = u32::MAX as usize + 1 - MEMORY_DEFAULT_PAGE_SIZE;
+        processor
+            .push_u32((last_size - 4 * MEMORY_DEFAULT_PAGE_SIZE) as u32)
+            .unwrap();
+        memory_grow(&mut processor).expect("[3] The method must succeed");
+        processor.pop_u32().unwrap();
+        assert!(
+            !processor.overflow_flag(),
+            "[3] The overflow flag must be clear"
+        );
+        memory_size(&mut processor).expect("[3] The method must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap() as usize,
+            last_size,
+            "[3] The memory size is incorrect"
+        );
+
+        // Case 4: grow a single byte more.
+        processor.push_u32(1).unwrap();
+        memory_grow(&mut processor).expect("[4] The method must succeed");
+        assert!(
+            processor.overflow_flag(),
+            "[4] The overflow flag must be set"
+        );
+        assert_eq!(
+            processor.memory().size(),
+            last_size,
+            "[4] The current memory size is incorrect"
+        );
     }
 
     #[test]
@@ -453,7 +533,7 @@ mod test {
             "[1] The stack pointer is incorrect"
         );
 
-        let page = &processor.memory().pages[0];
+        let page = processor.memory().page(0).unwrap();
         let range = &page[start_pointer as usize..(start_pointer + number_of_words) as usize];
         for byte in range {
             assert_eq!(byte, &value, "[1] The value is incorrect")
@@ -472,7 +552,7 @@ mod test {
             "[2] The stack pointer is incorrect"
         );
 
-        let page = &processor.memory().pages[0];
+        let page = processor.memory().page(0).unwrap();
         let range = &page[start_pointer as usize..(start_pointer + number_of_words) as usize];
         for (i, byte) in range.iter().enumerate() {
             match i % 2 {
@@ -495,7 +575,7 @@ mod test {
             "[3] The stack pointer is incorrect"
         );
 
-        let page = &processor.memory().pages[0];
+        let page = processor.memory().page(0).unwrap();
         let range = &page[start_pointer as usize..(start_pointer + number_of_words) as usize];
         for (i, byte) in range.iter().enumerate() {
             match i % 4 {
@@ -520,7 +600,7 @@ mod test {
             "[4] The stack pointer is incorrect"
         );
 
-        let page = &processor.memory().pages[0];
+        let page = processor.memory().page(0).unwrap();
         let range = &page[start_pointer as usize..(start_pointer + number_of_words) as usize];
         for (i, byte) in range.iter().enumerate() {
             match i % 8 {
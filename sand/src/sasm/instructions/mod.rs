@@ -1,12 +1,16 @@
 pub use arithmetic::*;
 pub use general::*;
+pub use local::*;
 pub use memory::*;
 pub use stack::*;
 
+use std::convert::TryInto;
+
 use crate::sasm::{Action, Processor};
 
 mod arithmetic;
 mod general;
+mod local;
 mod memory;
 mod stack;
 
@@ -21,6 +25,10 @@ pub enum Instruction {
     BranchIf16,
     BranchIf32,
     BranchIf64,
+    Call,
+    Return,
+    BranchTable,
+    CallHost,
 
     // Memory
     MemorySize = 12,
@@ -43,6 +51,16 @@ pub enum Instruction {
     ProgramDataLoad32,
     ProgramDataLoad64,
 
+    // Local
+    LocalGet8 = 31,
+    LocalGet16,
+    LocalGet32,
+    LocalGet64,
+    LocalSet8,
+    LocalSet16,
+    LocalSet32,
+    LocalSet64,
+
     // Stack
     Drop8 = 40,
     Drop16,
@@ -52,6 +70,26 @@ pub enum Instruction {
     Const16,
     Const32,
     Const64,
+    Dup8,
+    Dup16,
+    Dup32,
+    Dup64,
+    Swap8,
+    Swap16,
+    Swap32,
+    Swap64,
+    Over8,
+    Over16,
+    Over32,
+    Over64,
+    Rot8,
+    Rot16,
+    Rot32,
+    Rot64,
+    Pick8,
+    Pick16,
+    Pick32,
+    Pick64,
 
     // Arithmetic
     Extend8To16 = 70,
@@ -71,9 +109,558 @@ pub enum Instruction {
     Trunc64To8,
     Trunc64To16,
     Trunc64To32,
+
+    // Arithmetic - Integer
+    Add8 = 90,
+    Add16,
+    Add32,
+    Add64,
+    Sub8,
+    Sub16,
+    Sub32,
+    Sub64,
+    Mul8,
+    Mul16,
+    Mul32,
+    Mul64,
+    Div8,
+    Div16,
+    Div32,
+    Div64,
+    DivSign8,
+    DivSign16,
+    DivSign32,
+    DivSign64,
+    Rem8,
+    Rem16,
+    Rem32,
+    Rem64,
+    RemSign8,
+    RemSign16,
+    RemSign32,
+    RemSign64,
+
+    // Arithmetic - Bitwise
+    And8 = 120,
+    And16,
+    And32,
+    And64,
+    Or8,
+    Or16,
+    Or32,
+    Or64,
+    Xor8,
+    Xor16,
+    Xor32,
+    Xor64,
+    Not8,
+    Not16,
+    Not32,
+    Not64,
+    Shl8,
+    Shl16,
+    Shl32,
+    Shl64,
+    Shr8,
+    Shr16,
+    Shr32,
+    Shr64,
+    ShrSign8,
+    ShrSign16,
+    ShrSign32,
+    ShrSign64,
+    Rotl8,
+    Rotl16,
+    Rotl32,
+    Rotl64,
+    Rotr8,
+    Rotr16,
+    Rotr32,
+    Rotr64,
+    Popcnt8,
+    Popcnt16,
+    Popcnt32,
+    Popcnt64,
+    Clz8,
+    Clz16,
+    Clz32,
+    Clz64,
+    Ctz8,
+    Ctz16,
+    Ctz32,
+    Ctz64,
+
+    // Arithmetic - Comparison
+    Eq8 = 170,
+    Eq16,
+    Eq32,
+    Eq64,
+    Ne8,
+    Ne16,
+    Ne32,
+    Ne64,
+    Eqz8,
+    Eqz16,
+    Eqz32,
+    Eqz64,
+    Lt8,
+    Lt16,
+    Lt32,
+    Lt64,
+    LtSign8,
+    LtSign16,
+    LtSign32,
+    LtSign64,
+    Le8,
+    Le16,
+    Le32,
+    Le64,
+    LeSign8,
+    LeSign16,
+    LeSign32,
+    LeSign64,
+    Gt8,
+    Gt16,
+    Gt32,
+    Gt64,
+    GtSign8,
+    GtSign16,
+    GtSign32,
+    GtSign64,
+    Ge8,
+    Ge16,
+    Ge32,
+    Ge64,
+    GeSign8,
+    GeSign16,
+    GeSign32,
+    GeSign64,
+    EqF32,
+    EqF64,
+    NeF32,
+    NeF64,
+    LtF32,
+    LtF64,
+    LeF32,
+    LeF64,
+    GtF32,
+    GtF64,
+    GeF32,
+    GeF64,
+
+    // Arithmetic - Float
+    AddF32 = 226,
+    AddF64,
+    SubF32,
+    SubF64,
+    MulF32,
+    MulF64,
+    DivF32,
+    DivF64,
+    NegF32,
+    NegF64,
+    AbsF32,
+    AbsF64,
+    SqrtF32,
+    SqrtF64,
+    MinF32,
+    MinF64,
+    MaxF32,
+    MaxF64,
+    FloorF32,
+    FloorF64,
+    CeilF32,
+    CeilF64,
+    TruncF32,
+    TruncF64,
+    NearestF32,
+    NearestF64,
+    CopysignF32,
+    CopysignF64,
+
+    // Arithmetic - Conversion
+    Convert32ToF32 = 256,
+    ConvertSign32ToF32,
+    Convert32ToF64,
+    ConvertSign32ToF64,
+    Convert64ToF32,
+    ConvertSign64ToF32,
+    Convert64ToF64,
+    ConvertSign64ToF64,
+    TruncF32To32,
+    TruncSignF32To32,
+    TruncF32To64,
+    TruncSignF32To64,
+    TruncF64To32,
+    TruncSignF64To32,
+    TruncF64To64,
+    TruncSignF64To64,
+    TruncSatF32To32,
+    TruncSatSignF32To32,
+    TruncSatF32To64,
+    TruncSatSignF32To64,
+    TruncSatF64To32,
+    TruncSatSignF64To32,
+    TruncSatF64To64,
+    TruncSatSignF64To64,
+    PromoteF32ToF64,
+    DemoteF64ToF32,
+    ReinterpretF32To32,
+    ReinterpretF64To64,
+    Reinterpret32ToF32,
+    Reinterpret64ToF64,
+
+    // General - Relative
+    BranchRel = 286,
+    BranchRelIf8,
+    BranchRelIf16,
+    BranchRelIf32,
+    BranchRelIf64,
+    CallRel,
+
+    // Stack - Select
+    Select8 = 292,
+    Select16,
+    Select32,
+    Select64,
+
+    // Arithmetic - Integer - Signed
+    AddSign8 = 296,
+    AddSign16,
+    AddSign32,
+    AddSign64,
+    SubSign8,
+    SubSign16,
+    SubSign32,
+    SubSign64,
+    MulSign8,
+    MulSign16,
+    MulSign32,
+    MulSign64,
 }
 
 impl Instruction {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Gets the instruction assigned to the opcode `index` or `None` if it is unassigned.
+    pub fn from_index(index: usize) -> Option<Instruction> {
+        match index {
+            0 => Some(Instruction::Unreachable),
+            1 => Some(Instruction::Nop),
+            2 => Some(Instruction::Debug),
+            3 => Some(Instruction::Branch),
+            4 => Some(Instruction::BranchIf8),
+            5 => Some(Instruction::BranchIf16),
+            6 => Some(Instruction::BranchIf32),
+            7 => Some(Instruction::BranchIf64),
+            8 => Some(Instruction::Call),
+            9 => Some(Instruction::Return),
+            10 => Some(Instruction::BranchTable),
+            11 => Some(Instruction::CallHost),
+            12 => Some(Instruction::MemorySize),
+            13 => Some(Instruction::MemoryGrow),
+            14 => Some(Instruction::MemoryFill8),
+            15 => Some(Instruction::MemoryFill16),
+            16 => Some(Instruction::MemoryFill32),
+            17 => Some(Instruction::MemoryFill64),
+            18 => Some(Instruction::MemoryCopy),
+            19 => Some(Instruction::MemoryLoad8),
+            20 => Some(Instruction::MemoryLoad16),
+            21 => Some(Instruction::MemoryLoad32),
+            22 => Some(Instruction::MemoryLoad64),
+            23 => Some(Instruction::MemoryStore8),
+            24 => Some(Instruction::MemoryStore16),
+            25 => Some(Instruction::MemoryStore32),
+            26 => Some(Instruction::MemoryStore64),
+            27 => Some(Instruction::ProgramDataLoad8),
+            28 => Some(Instruction::ProgramDataLoad16),
+            29 => Some(Instruction::ProgramDataLoad32),
+            30 => Some(Instruction::ProgramDataLoad64),
+            31 => Some(Instruction::LocalGet8),
+            32 => Some(Instruction::LocalGet16),
+            33 => Some(Instruction::LocalGet32),
+            34 => Some(Instruction::LocalGet64),
+            35 => Some(Instruction::LocalSet8),
+            36 => Some(Instruction::LocalSet16),
+            37 => Some(Instruction::LocalSet32),
+            38 => Some(Instruction::LocalSet64),
+            40 => Some(Instruction::Drop8),
+            41 => Some(Instruction::Drop16),
+            42 => Some(Instruction::Drop32),
+            43 => Some(Instruction::Drop64),
+            44 => Some(Instruction::Const8),
+            45 => Some(Instruction::Const16),
+            46 => Some(Instruction::Const32),
+            47 => Some(Instruction::Const64),
+            48 => Some(Instruction::Dup8),
+            49 => Some(Instruction::Dup16),
+            50 => Some(Instruction::Dup32),
+            51 => Some(Instruction::Dup64),
+            52 => Some(Instruction::Swap8),
+            53 => Some(Instruction::Swap16),
+            54 => Some(Instruction::Swap32),
+            55 => Some(Instruction::Swap64),
+            56 => Some(Instruction::Over8),
+            57 => Some(Instruction::Over16),
+            58 => Some(Instruction::Over32),
+            59 => Some(Instruction::Over64),
+            60 => Some(Instruction::Rot8),
+            61 => Some(Instruction::Rot16),
+            62 => Some(Instruction::Rot32),
+            63 => Some(Instruction::Rot64),
+            64 => Some(Instruction::Pick8),
+            65 => Some(Instruction::Pick16),
+            66 => Some(Instruction::Pick32),
+            67 => Some(Instruction::Pick64),
+            70 => Some(Instruction::Extend8To16),
+            71 => Some(Instruction::Extend8To32),
+            72 => Some(Instruction::Extend16To32),
+            73 => Some(Instruction::Extend8To64),
+            74 => Some(Instruction::Extend16To64),
+            75 => Some(Instruction::Extend32To64),
+            76 => Some(Instruction::ExtendSign8To16),
+            77 => Some(Instruction::ExtendSign8To32),
+            78 => Some(Instruction::ExtendSign16To32),
+            79 => Some(Instruction::ExtendSign8To64),
+            80 => Some(Instruction::ExtendSign16To64),
+            81 => Some(Instruction::ExtendSign32To64),
+            82 => Some(Instruction::Trunc16To8),
+            83 => Some(Instruction::Trunc32To8),
+            84 => Some(Instruction::Trunc64To8),
+            85 => Some(Instruction::Trunc64To16),
+            86 => Some(Instruction::Trunc64To32),
+            90 => Some(Instruction::Add8),
+            91 => Some(Instruction::Add16),
+            92 => Some(Instruction::Add32),
+            93 => Some(Instruction::Add64),
+            94 => Some(Instruction::Sub8),
+            95 => Some(Instruction::Sub16),
+            96 => Some(Instruction::Sub32),
+            97 => Some(Instruction::Sub64),
+            98 => Some(Instruction::Mul8),
+            99 => Some(Instruction::Mul16),
+            100 => Some(Instruction::Mul32),
+            101 => Some(Instruction::Mul64),
+            102 => Some(Instruction::Div8),
+            103 => Some(Instruction::Div16),
+            104 => Some(Instruction::Div32),
+            105 => Some(Instruction::Div64),
+            106 => Some(Instruction::DivSign8),
+            107 => Some(Instruction::DivSign16),
+            108 => Some(Instruction::DivSign32),
+            109 => Some(Instruction::DivSign64),
+            110 => Some(Instruction::Rem8),
+            111 => Some(Instruction::Rem16),
+            112 => Some(Instruction::Rem32),
+            113 => Some(Instruction::Rem64),
+            114 => Some(Instruction::RemSign8),
+            115 => Some(Instruction::RemSign16),
+            116 => Some(Instruction::RemSign32),
+            117 => Some(Instruction::RemSign64),
+            120 => Some(Instruction::And8),
+            121 => Some(Instruction::And16),
+            122 => Some(Instruction::And32),
+            123 => Some(Instruction::And64),
+            124 => Some(Instruction::Or8),
+            125 => Some(Instruction::Or16),
+            126 => Some(Instruction::Or32),
+            127 => Some(Instruction::Or64),
+            128 => Some(Instruction::Xor8),
+            129 => Some(Instruction::Xor16),
+            130 => Some(Instruction::Xor32),
+            131 => Some(Instruction::Xor64),
+            132 => Some(Instruction::Not8),
+            133 => Some(Instruction::Not16),
+            134 => Some(Instruction::Not32),
+            135 => Some(Instruction::Not64),
+            136 => Some(Instruction::Shl8),
+            137 => Some(Instruction::Shl16),
+            138 => Some(Instruction::Shl32),
+            139 => Some(Instruction::Shl64),
+            140 => Some(Instruction::Shr8),
+            141 => Some(Instruction::Shr16),
+            142 => Some(Instruction::Shr32),
+            143 => Some(Instruction::Shr64),
+            144 => Some(Instruction::ShrSign8),
+            145 => Some(Instruction::ShrSign16),
+            146 => Some(Instruction::ShrSign32),
+            147 => Some(Instruction::ShrSign64),
+            148 => Some(Instruction::Rotl8),
+            149 => Some(Instruction::Rotl16),
+            150 => Some(Instruction::Rotl32),
+            151 => Some(Instruction::Rotl64),
+            152 => Some(Instruction::Rotr8),
+            153 => Some(Instruction::Rotr16),
+            154 => Some(Instruction::Rotr32),
+            155 => Some(Instruction::Rotr64),
+            156 => Some(Instruction::Popcnt8),
+            157 => Some(Instruction::Popcnt16),
+            158 => Some(Instruction::Popcnt32),
+            159 => Some(Instruction::Popcnt64),
+            160 => Some(Instruction::Clz8),
+            161 => Some(Instruction::Clz16),
+            162 => Some(Instruction::Clz32),
+            163 => Some(Instruction::Clz64),
+            164 => Some(Instruction::Ctz8),
+            165 => Some(Instruction::Ctz16),
+            166 => Some(Instruction::Ctz32),
+            167 => Some(Instruction::Ctz64),
+            170 => Some(Instruction::Eq8),
+            171 => Some(Instruction::Eq16),
+            172 => Some(Instruction::Eq32),
+            173 => Some(Instruction::Eq64),
+            174 => Some(Instruction::Ne8),
+            175 => Some(Instruction::Ne16),
+            176 => Some(Instruction::Ne32),
+            177 => Some(Instruction::Ne64),
+            178 => Some(Instruction::Eqz8),
+            179 => Some(Instruction::Eqz16),
+            180 => Some(Instruction::Eqz32),
+            181 => Some(Instruction::Eqz64),
+            182 => Some(Instruction::Lt8),
+            183 => Some(Instruction::Lt16),
+            184 => Some(Instruction::Lt32),
+            185 => Some(Instruction::Lt64),
+            186 => Some(Instruction::LtSign8),
+            187 => Some(Instruction::LtSign16),
+            188 => Some(Instruction::LtSign32),
+            189 => Some(Instruction::LtSign64),
+            190 => Some(Instruction::Le8),
+            191 => Some(Instruction::Le16),
+            192 => Some(Instruction::Le32),
+            193 => Some(Instruction::Le64),
+            194 => Some(Instruction::LeSign8),
+            195 => Some(Instruction::LeSign16),
+            196 => Some(Instruction::LeSign32),
+            197 => Some(Instruction::LeSign64),
+            198 => Some(Instruction::Gt8),
+            199 => Some(Instruction::Gt16),
+            200 => Some(Instruction::Gt32),
+            201 => Some(Instruction::Gt64),
+            202 => Some(Instruction::GtSign8),
+            203 => Some(Instruction::GtSign16),
+            204 => Some(Instruction::GtSign32),
+            205 => Some(Instruction::GtSign64),
+            206 => Some(Instruction::Ge8),
+            207 => Some(Instruction::Ge16),
+            208 => Some(Instruction::Ge32),
+            209 => Some(Instruction::Ge64),
+            210 => Some(Instruction::GeSign8),
+            211 => Some(Instruction::GeSign16),
+            212 => Some(Instruction::GeSign32),
+            213 => Some(Instruction::GeSign64),
+            214 => Some(Instruction::EqF32),
+            215 => Some(Instruction::EqF64),
+            216 => Some(Instruction::NeF32),
+            217 => Some(Instruction::NeF64),
+            218 => Some(Instruction::LtF32),
+            219 => Some(Instruction::LtF64),
+            220 => Some(Instruction::LeF32),
+            221 => Some(Instruction::LeF64),
+            222 => Some(Instruction::GtF32),
+            223 => Some(Instruction::GtF64),
+            224 => Some(Instruction::GeF32),
+            225 => Some(Instruction::GeF64),
+            226 => Some(Instruction::AddF32),
+            227 => Some(Instruction::AddF64),
+            228 => Some(Instruction::SubF32),
+            229 => Some(Instruction::SubF64),
+            230 => Some(Instruction::MulF32),
+            231 => Some(Instruction::MulF64),
+            232 => Some(Instruction::DivF32),
+            233 => Some(Instruction::DivF64),
+            234 => Some(Instruction::NegF32),
+            235 => Some(Instruction::NegF64),
+            236 => Some(Instruction::AbsF32),
+            237 => Some(Instruction::AbsF64),
+            238 => Some(Instruction::SqrtF32),
+            239 => Some(Instruction::SqrtF64),
+            240 => Some(Instruction::MinF32),
+            241 => Some(Instruction::MinF64),
+            242 => Some(Instruction::MaxF32),
+            243 => Some(Instruction::MaxF64),
+            244 => Some(Instruction::FloorF32),
+            245 => Some(Instruction::FloorF64),
+            246 => Some(Instruction::CeilF32),
+            247 => Some(Instruction::CeilF64),
+            248 => Some(Instruction::TruncF32),
+            249 => Some(Instruction::TruncF64),
+            250 => Some(Instruction::NearestF32),
+            251 => Some(Instruction::NearestF64),
+            252 => Some(Instruction::CopysignF32),
+            253 => Some(Instruction::CopysignF64),
+            256 => Some(Instruction::Convert32ToF32),
+            257 => Some(Instruction::ConvertSign32ToF32),
+            258 => Some(Instruction::Convert32ToF64),
+            259 => Some(Instruction::ConvertSign32ToF64),
+            260 => Some(Instruction::Convert64ToF32),
+            261 => Some(Instruction::ConvertSign64ToF32),
+            262 => Some(Instruction::Convert64ToF64),
+            263 => Some(Instruction::ConvertSign64ToF64),
+            264 => Some(Instruction::TruncF32To32),
+            265 => Some(Instruction::TruncSignF32To32),
+            266 => Some(Instruction::TruncF32To64),
+            267 => Some(Instruction::TruncSignF32To64),
+            268 => Some(Instruction::TruncF64To32),
+            269 => Some(Instruction::TruncSignF64To32),
+            270 => Some(Instruction::TruncF64To64),
+            271 => Some(Instruction::TruncSignF64To64),
+            272 => Some(Instruction::TruncSatF32To32),
+            273 => Some(Instruction::TruncSatSignF32To32),
+            274 => Some(Instruction::TruncSatF32To64),
+            275 => Some(Instruction::TruncSatSignF32To64),
+            276 => Some(Instruction::TruncSatF64To32),
+            277 => Some(Instruction::TruncSatSignF64To32),
+            278 => Some(Instruction::TruncSatF64To64),
+            279 => Some(Instruction::TruncSatSignF64To64),
+            280 => Some(Instruction::PromoteF32ToF64),
+            281 => Some(Instruction::DemoteF64ToF32),
+            282 => Some(Instruction::ReinterpretF32To32),
+            283 => Some(Instruction::ReinterpretF64To64),
+            284 => Some(Instruction::Reinterpret32ToF32),
+            285 => Some(Instruction::Reinterpret64ToF64),
+            286 => Some(Instruction::BranchRel),
+            287 => Some(Instruction::BranchRelIf8),
+            288 => Some(Instruction::BranchRelIf16),
+            289 => Some(Instruction::BranchRelIf32),
+            290 => Some(Instruction::BranchRelIf64),
+            291 => Some(Instruction::CallRel),
+            292 => Some(Instruction::Select8),
+            293 => Some(Instruction::Select16),
+            294 => Some(Instruction::Select32),
+            295 => Some(Instruction::Select64),
+            296 => Some(Instruction::AddSign8),
+            297 => Some(Instruction::AddSign16),
+            298 => Some(Instruction::AddSign32),
+            299 => Some(Instruction::AddSign64),
+            300 => Some(Instruction::SubSign8),
+            301 => Some(Instruction::SubSign16),
+            302 => Some(Instruction::SubSign32),
+            303 => Some(Instruction::SubSign64),
+            304 => Some(Instruction::MulSign8),
+            305 => Some(Instruction::MulSign16),
+            306 => Some(Instruction::MulSign32),
+            307 => Some(Instruction::MulSign64),
+            _ => None,
+        }
+    }
+
+    /// Gets the instruction whose mnemonic is `name`.
+    pub fn from_name(name: &str) -> Option<Instruction> {
+        (0..INSTRUCTION_LIST.len() + EXTENDED_INSTRUCTION_LIST.len())
+            .filter_map(Instruction::from_index)
+            .find(|v| v.name() == name)
+    }
+
+    /// Reads the opcode placed at the beginning of `code` and returns its index, which can
+    /// be unassigned, or `None` if `code` ends before the opcode does.
+    pub fn read_index(code: &[u8]) -> Option<usize> {
+        match *code.first()? {
+            EXTENDED_OPCODE => code.get(1).map(|v| INSTRUCTION_LIST.len() + *v as usize),
+            v => Some(v as usize),
+        }
+    }
+
     // GETTERS ----------------------------------------------------------------
 
     pub fn index(&self) -> usize {
@@ -82,12 +669,702 @@ impl Instruction {
 
     pub fn method(&self) -> fn(&mut Processor) -> Result<(), Action> {
         let index = *self as usize;
-        INSTRUCTION_LIST[index]
+        if index < INSTRUCTION_LIST.len() {
+            INSTRUCTION_LIST[index]
+        } else {
+            EXTENDED_INSTRUCTION_LIST[index - INSTRUCTION_LIST.len()]
+        }
+    }
+
+    /// The number of bytes of the opcode: 1 for the base set and 2 for the extended set.
+    pub fn opcode_size(&self) -> usize {
+        if self.index() < INSTRUCTION_LIST.len() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// The number of bytes the instruction occupies in the code, including its immediate values.
+    pub fn size(&self) -> usize {
+        self.opcode_size() + self.immediate_size()
+    }
+
+    /// Reads the number of bytes the instruction placed at the beginning of `code` occupies,
+    /// including the entries of the table of `branch_table`, or `None` if `code` ends before
+    /// the instruction does.
+    pub fn read_size(&self, code: &[u8]) -> Option<usize> {
+        let size = self.size();
+        let size = match self {
+            Instruction::BranchTable => {
+                let count = code.get(self.opcode_size()..size)?;
+                let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+                size + (count + 1) * std::mem::size_of::<i32>()
+            }
+            _ => size,
+        };
+
+        if size > code.len() {
+            None
+        } else {
+            Some(size)
+        }
+    }
+
+    /// The mnemonic of the instruction, which matches the name of its method.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instruction::Unreachable => "unreachable",
+            Instruction::Nop => "nop",
+            Instruction::Debug => "debug",
+            Instruction::Branch => "branch",
+            Instruction::BranchIf8 => "branch_if_8",
+            Instruction::BranchIf16 => "branch_if_16",
+            Instruction::BranchIf32 => "branch_if_32",
+            Instruction::BranchIf64 => "branch_if_64",
+            Instruction::Call => "call",
+            Instruction::Return => "return",
+            Instruction::BranchTable => "branch_table",
+            Instruction::CallHost => "call_host",
+            Instruction::MemorySize => "memory_size",
+            Instruction::MemoryGrow => "memory_grow",
+            Instruction::MemoryFill8 => "memory_fill_8",
+            Instruction::MemoryFill16 => "memory_fill_16",
+            Instruction::MemoryFill32 => "memory_fill_32",
+            Instruction::MemoryFill64 => "memory_fill_64",
+            Instruction::MemoryCopy => "memory_copy",
+            Instruction::MemoryLoad8 => "memory_load_8",
+            Instruction::MemoryLoad16 => "memory_load_16",
+            Instruction::MemoryLoad32 => "memory_load_32",
+            Instruction::MemoryLoad64 => "memory_load_64",
+            Instruction::MemoryStore8 => "memory_store_8",
+            Instruction::MemoryStore16 => "memory_store_16",
+            Instruction::MemoryStore32 => "memory_store_32",
+            Instruction::MemoryStore64 => "memory_store_64",
+            Instruction::ProgramDataLoad8 => "program_data_load_8",
+            Instruction::ProgramDataLoad16 => "program_data_load_16",
+            Instruction::ProgramDataLoad32 => "program_data_load_32",
+            Instruction::ProgramDataLoad64 => "program_data_load_64",
+            Instruction::LocalGet8 => "local_get_8",
+            Instruction::LocalGet16 => "local_get_16",
+            Instruction::LocalGet32 => "local_get_32",
+            Instruction::LocalGet64 => "local_get_64",
+            Instruction::LocalSet8 => "local_set_8",
+            Instruction::LocalSet16 => "local_set_16",
+            Instruction::LocalSet32 => "local_set_32",
+            Instruction::LocalSet64 => "local_set_64",
+            Instruction::Drop8 => "drop_8",
+            Instruction::Drop16 => "drop_16",
+            Instruction::Drop32 => "drop_32",
+            Instruction::Drop64 => "drop_64",
+            Instruction::Const8 => "const_8",
+            Instruction::Const16 => "const_16",
+            Instruction::Const32 => "const_32",
+            Instruction::Const64 => "const_64",
+            Instruction::Dup8 => "dup_8",
+            Instruction::Dup16 => "dup_16",
+            Instruction::Dup32 => "dup_32",
+            Instruction::Dup64 => "dup_64",
+            Instruction::Swap8 => "swap_8",
+            Instruction::Swap16 => "swap_16",
+            Instruction::Swap32 => "swap_32",
+            Instruction::Swap64 => "swap_64",
+            Instruction::Over8 => "over_8",
+            Instruction::Over16 => "over_16",
+            Instruction::Over32 => "over_32",
+            Instruction::Over64 => "over_64",
+            Instruction::Rot8 => "rot_8",
+            Instruction::Rot16 => "rot_16",
+            Instruction::Rot32 => "rot_32",
+            Instruction::Rot64 => "rot_64",
+            Instruction::Pick8 => "pick_8",
+            Instruction::Pick16 => "pick_16",
+            Instruction::Pick32 => "pick_32",
+            Instruction::Pick64 => "pick_64",
+            Instruction::Extend8To16 => "extend_8_to_16",
+            Instruction::Extend8To32 => "extend_8_to_32",
+            Instruction::Extend16To32 => "extend_16_to_32",
+            Instruction::Extend8To64 => "extend_8_to_64",
+            Instruction::Extend16To64 => "extend_16_to_64",
+            Instruction::Extend32To64 => "extend_32_to_64",
+            Instruction::ExtendSign8To16 => "extend_sign_8_to_16",
+            Instruction::ExtendSign8To32 => "extend_sign_8_to_32",
+            Instruction::ExtendSign16To32 => "extend_sign_16_to_32",
+            Instruction::ExtendSign8To64 => "extend_sign_8_to_64",
+            Instruction::ExtendSign16To64 => "extend_sign_16_to_64",
+            Instruction::ExtendSign32To64 => "extend_sign_32_to_64",
+            Instruction::Trunc16To8 => "trunc_16_to_8",
+            Instruction::Trunc32To8 => "trunc_32_to_8",
+            Instruction::Trunc64To8 => "trunc_64_to_8",
+            Instruction::Trunc64To16 => "trunc_64_to_16",
+            Instruction::Trunc64To32 => "trunc_64_to_32",
+            Instruction::Add8 => "add_8",
+            Instruction::Add16 => "add_16",
+            Instruction::Add32 => "add_32",
+            Instruction::Add64 => "add_64",
+            Instruction::Sub8 => "sub_8",
+            Instruction::Sub16 => "sub_16",
+            Instruction::Sub32 => "sub_32",
+            Instruction::Sub64 => "sub_64",
+            Instruction::Mul8 => "mul_8",
+            Instruction::Mul16 => "mul_16",
+            Instruction::Mul32 => "mul_32",
+            Instruction::Mul64 => "mul_64",
+            Instruction::Div8 => "div_8",
+            Instruction::Div16 => "div_16",
+            Instruction::Div32 => "div_32",
+            Instruction::Div64 => "div_64",
+            Instruction::DivSign8 => "div_sign_8",
+            Instruction::DivSign16 => "div_sign_16",
+            Instruction::DivSign32 => "div_sign_32",
+            Instruction::DivSign64 => "div_sign_64",
+            Instruction::Rem8 => "rem_8",
+            Instruction::Rem16 => "rem_16",
+            Instruction::Rem32 => "rem_32",
+            Instruction::Rem64 => "rem_64",
+            Instruction::RemSign8 => "rem_sign_8",
+            Instruction::RemSign16 => "rem_sign_16",
+            Instruction::RemSign32 => "rem_sign_32",
+            Instruction::RemSign64 => "rem_sign_64",
+            Instruction::And8 => "and_8",
+            Instruction::And16 => "and_16",
+            Instruction::And32 => "and_32",
+            Instruction::And64 => "and_64",
+            Instruction::Or8 => "or_8",
+            Instruction::Or16 => "or_16",
+            Instruction::Or32 => "or_32",
+            Instruction::Or64 => "or_64",
+            Instruction::Xor8 => "xor_8",
+            Instruction::Xor16 => "xor_16",
+            Instruction::Xor32 => "xor_32",
+            Instruction::Xor64 => "xor_64",
+            Instruction::Not8 => "not_8",
+            Instruction::Not16 => "not_16",
+            Instruction::Not32 => "not_32",
+            Instruction::Not64 => "not_64",
+            Instruction::Shl8 => "shl_8",
+            Instruction::Shl16 => "shl_16",
+            Instruction::Shl32 => "shl_32",
+            Instruction::Shl64 => "shl_64",
+            Instruction::Shr8 => "shr_8",
+            Instruction::Shr16 => "shr_16",
+            Instruction::Shr32 => "shr_32",
+            Instruction::Shr64 => "shr_64",
+            Instruction::ShrSign8 => "shr_sign_8",
+            Instruction::ShrSign16 => "shr_sign_16",
+            Instruction::ShrSign32 => "shr_sign_32",
+            Instruction::ShrSign64 => "shr_sign_64",
+            Instruction::Rotl8 => "rotl_8",
+            Instruction::Rotl16 => "rotl_16",
+            Instruction::Rotl32 => "rotl_32",
+            Instruction::Rotl64 => "rotl_64",
+            Instruction::Rotr8 => "rotr_8",
+            Instruction::Rotr16 => "rotr_16",
+            Instruction::Rotr32 => "rotr_32",
+            Instruction::Rotr64 => "rotr_64",
+            Instruction::Popcnt8 => "popcnt_8",
+            Instruction::Popcnt16 => "popcnt_16",
+            Instruction::Popcnt32 => "popcnt_32",
+            Instruction::Popcnt64 => "popcnt_64",
+            Instruction::Clz8 => "clz_8",
+            Instruction::Clz16 => "clz_16",
+            Instruction::Clz32 => "clz_32",
+            Instruction::Clz64 => "clz_64",
+            Instruction::Ctz8 => "ctz_8",
+            Instruction::Ctz16 => "ctz_16",
+            Instruction::Ctz32 => "ctz_32",
+            Instruction::Ctz64 => "ctz_64",
+            Instruction::Eq8 => "eq_8",
+            Instruction::Eq16 => "eq_16",
+            Instruction::Eq32 => "eq_32",
+            Instruction::Eq64 => "eq_64",
+            Instruction::Ne8 => "ne_8",
+            Instruction::Ne16 => "ne_16",
+            Instruction::Ne32 => "ne_32",
+            Instruction::Ne64 => "ne_64",
+            Instruction::Eqz8 => "eqz_8",
+            Instruction::Eqz16 => "eqz_16",
+            Instruction::Eqz32 => "eqz_32",
+            Instruction::Eqz64 => "eqz_64",
+            Instruction::Lt8 => "lt_8",
+            Instruction::Lt16 => "lt_16",
+            Instruction::Lt32 => "lt_32",
+            Instruction::Lt64 => "lt_64",
+            Instruction::LtSign8 => "lt_sign_8",
+            Instruction::LtSign16 => "lt_sign_16",
+            Instruction::LtSign32 => "lt_sign_32",
+            Instruction::LtSign64 => "lt_sign_64",
+            Instruction::Le8 => "le_8",
+            Instruction::Le16 => "le_16",
+            Instruction::Le32 => "le_32",
+            Instruction::Le64 => "le_64",
+            Instruction::LeSign8 => "le_sign_8",
+            Instruction::LeSign16 => "le_sign_16",
+            Instruction::LeSign32 => "le_sign_32",
+            Instruction::LeSign64 => "le_sign_64",
+            Instruction::Gt8 => "gt_8",
+            Instruction::Gt16 => "gt_16",
+            Instruction::Gt32 => "gt_32",
+            Instruction::Gt64 => "gt_64",
+            Instruction::GtSign8 => "gt_sign_8",
+            Instruction::GtSign16 => "gt_sign_16",
+            Instruction::GtSign32 => "gt_sign_32",
+            Instruction::GtSign64 => "gt_sign_64",
+            Instruction::Ge8 => "ge_8",
+            Instruction::Ge16 => "ge_16",
+            Instruction::Ge32 => "ge_32",
+            Instruction::Ge64 => "ge_64",
+            Instruction::GeSign8 => "ge_sign_8",
+            Instruction::GeSign16 => "ge_sign_16",
+            Instruction::GeSign32 => "ge_sign_32",
+            Instruction::GeSign64 => "ge_sign_64",
+            Instruction::EqF32 => "eq_f32",
+            Instruction::EqF64 => "eq_f64",
+            Instruction::NeF32 => "ne_f32",
+            Instruction::NeF64 => "ne_f64",
+            Instruction::LtF32 => "lt_f32",
+            Instruction::LtF64 => "lt_f64",
+            Instruction::LeF32 => "le_f32",
+            Instruction::LeF64 => "le_f64",
+            Instruction::GtF32 => "gt_f32",
+            Instruction::GtF64 => "gt_f64",
+            Instruction::GeF32 => "ge_f32",
+            Instruction::GeF64 => "ge_f64",
+            Instruction::AddF32 => "add_f32",
+            Instruction::AddF64 => "add_f64",
+            Instruction::SubF32 => "sub_f32",
+            Instruction::SubF64 => "sub_f64",
+            Instruction::MulF32 => "mul_f32",
+            Instruction::MulF64 => "mul_f64",
+            Instruction::DivF32 => "div_f32",
+            Instruction::DivF64 => "div_f64",
+            Instruction::NegF32 => "neg_f32",
+            Instruction::NegF64 => "neg_f64",
+            Instruction::AbsF32 => "abs_f32",
+            Instruction::AbsF64 => "abs_f64",
+            Instruction::SqrtF32 => "sqrt_f32",
+            Instruction::SqrtF64 => "sqrt_f64",
+            Instruction::MinF32 => "min_f32",
+            Instruction::MinF64 => "min_f64",
+            Instruction::MaxF32 => "max_f32",
+            Instruction::MaxF64 => "max_f64",
+            Instruction::FloorF32 => "floor_f32",
+            Instruction::FloorF64 => "floor_f64",
+            Instruction::CeilF32 => "ceil_f32",
+            Instruction::CeilF64 => "ceil_f64",
+            Instruction::TruncF32 => "trunc_f32",
+            Instruction::TruncF64 => "trunc_f64",
+            Instruction::NearestF32 => "nearest_f32",
+            Instruction::NearestF64 => "nearest_f64",
+            Instruction::CopysignF32 => "copysign_f32",
+            Instruction::CopysignF64 => "copysign_f64",
+            Instruction::Convert32ToF32 => "convert_32_to_f32",
+            Instruction::ConvertSign32ToF32 => "convert_sign_32_to_f32",
+            Instruction::Convert32ToF64 => "convert_32_to_f64",
+            Instruction::ConvertSign32ToF64 => "convert_sign_32_to_f64",
+            Instruction::Convert64ToF32 => "convert_64_to_f32",
+            Instruction::ConvertSign64ToF32 => "convert_sign_64_to_f32",
+            Instruction::Convert64ToF64 => "convert_64_to_f64",
+            Instruction::ConvertSign64ToF64 => "convert_sign_64_to_f64",
+            Instruction::TruncF32To32 => "trunc_f32_to_32",
+            Instruction::TruncSignF32To32 => "trunc_sign_f32_to_32",
+            Instruction::TruncF32To64 => "trunc_f32_to_64",
+            Instruction::TruncSignF32To64 => "trunc_sign_f32_to_64",
+            Instruction::TruncF64To32 => "trunc_f64_to_32",
+            Instruction::TruncSignF64To32 => "trunc_sign_f64_to_32",
+            Instruction::TruncF64To64 => "trunc_f64_to_64",
+            Instruction::TruncSignF64To64 => "trunc_sign_f64_to_64",
+            Instruction::TruncSatF32To32 => "trunc_sat_f32_to_32",
+            Instruction::TruncSatSignF32To32 => "trunc_sat_sign_f32_to_32",
+            Instruction::TruncSatF32To64 => "trunc_sat_f32_to_64",
+            Instruction::TruncSatSignF32To64 => "trunc_sat_sign_f32_to_64",
+            Instruction::TruncSatF64To32 => "trunc_sat_f64_to_32",
+            Instruction::TruncSatSignF64To32 => "trunc_sat_sign_f64_to_32",
+            Instruction::TruncSatF64To64 => "trunc_sat_f64_to_64",
+            Instruction::TruncSatSignF64To64 => "trunc_sat_sign_f64_to_64",
+            Instruction::PromoteF32ToF64 => "promote_f32_to_f64",
+            Instruction::DemoteF64ToF32 => "demote_f64_to_f32",
+            Instruction::ReinterpretF32To32 => "reinterpret_f32_to_32",
+            Instruction::ReinterpretF64To64 => "reinterpret_f64_to_64",
+            Instruction::Reinterpret32ToF32 => "reinterpret_32_to_f32",
+            Instruction::Reinterpret64ToF64 => "reinterpret_64_to_f64",
+            Instruction::BranchRel => "branch_rel",
+            Instruction::BranchRelIf8 => "branch_rel_if_8",
+            Instruction::BranchRelIf16 => "branch_rel_if_16",
+            Instruction::BranchRelIf32 => "branch_rel_if_32",
+            Instruction::BranchRelIf64 => "branch_rel_if_64",
+            Instruction::CallRel => "call_rel",
+            Instruction::Select8 => "select_8",
+            Instruction::Select16 => "select_16",
+            Instruction::Select32 => "select_32",
+            Instruction::Select64 => "select_64",
+            Instruction::AddSign8 => "add_sign_8",
+            Instruction::AddSign16 => "add_sign_16",
+            Instruction::AddSign32 => "add_sign_32",
+            Instruction::AddSign64 => "add_sign_64",
+            Instruction::SubSign8 => "sub_sign_8",
+            Instruction::SubSign16 => "sub_sign_16",
+            Instruction::SubSign32 => "sub_sign_32",
+            Instruction::SubSign64 => "sub_sign_64",
+            Instruction::MulSign8 => "mul_sign_8",
+            Instruction::MulSign16 => "mul_sign_16",
+            Instruction::MulSign32 => "mul_sign_32",
+            Instruction::MulSign64 => "mul_sign_64",
+        }
+    }
+
+    /// The number of bytes of immediate values that follow the opcode in the code.
+    pub fn immediate_size(&self) -> usize {
+        match self {
+            Instruction::BranchTable
+            | Instruction::CallHost
+            | Instruction::LocalGet8
+            | Instruction::LocalGet16
+            | Instruction::LocalGet32
+            | Instruction::LocalGet64
+            | Instruction::LocalSet8
+            | Instruction::LocalSet16
+            | Instruction::LocalSet32
+            | Instruction::LocalSet64 => 4,
+            Instruction::Const8 => 1,
+            Instruction::Const16 => 2,
+            Instruction::Const32 => 4,
+            Instruction::Const64 => 8,
+            Instruction::Pick8
+            | Instruction::Pick16
+            | Instruction::Pick32
+            | Instruction::Pick64 => 1,
+            Instruction::BranchRel
+            | Instruction::BranchRelIf8
+            | Instruction::BranchRelIf16
+            | Instruction::BranchRelIf32
+            | Instruction::BranchRelIf64
+            | Instruction::CallRel => 4,
+            _ => 0,
+        }
+    }
+
+    /// Whether the immediate value of the instruction is a code offset relative to the end
+    /// of the instruction.
+    pub fn is_relative_branch(&self) -> bool {
+        matches!(
+            self,
+            Instruction::BranchRel
+                | Instruction::BranchRelIf8
+                | Instruction::BranchRelIf16
+                | Instruction::BranchRelIf32
+                | Instruction::BranchRelIf64
+                | Instruction::CallRel
+        )
+    }
+
+    /// The number of bytes the instruction pops from the stack and pushes to it.
+    pub fn stack_effect(&self) -> (usize, usize) {
+        match self {
+            Instruction::Unreachable | Instruction::Nop | Instruction::Debug => (0, 0),
+            Instruction::Branch => (4, 0),
+            Instruction::BranchIf8 => (5, 0),
+            Instruction::BranchIf16 => (6, 0),
+            Instruction::BranchIf32 => (8, 0),
+            Instruction::BranchIf64 => (12, 0),
+            Instruction::Call => (4, 0),
+            Instruction::Return => (0, 0),
+            Instruction::BranchTable => (4, 0),
+            Instruction::CallHost => (0, 0),
+            Instruction::MemorySize => (0, 4),
+            Instruction::MemoryGrow => (4, 4),
+            Instruction::MemoryFill8 => (9, 0),
+            Instruction::MemoryFill16 => (10, 0),
+            Instruction::MemoryFill32 => (12, 0),
+            Instruction::MemoryFill64 => (16, 0),
+            Instruction::MemoryCopy => (12, 0),
+            Instruction::MemoryLoad8 => (4, 1),
+            Instruction::MemoryLoad16 => (4, 2),
+            Instruction::MemoryLoad32 => (4, 4),
+            Instruction::MemoryLoad64 => (4, 8),
+            Instruction::MemoryStore8 => (5, 0),
+            Instruction::MemoryStore16 => (6, 0),
+            Instruction::MemoryStore32 => (8, 0),
+            Instruction::MemoryStore64 => (12, 0),
+            Instruction::ProgramDataLoad8 => (4, 1),
+            Instruction::ProgramDataLoad16 => (4, 2),
+            Instruction::ProgramDataLoad32 => (4, 4),
+            Instruction::ProgramDataLoad64 => (4, 8),
+            Instruction::LocalGet8 => (0, 1),
+            Instruction::LocalGet16 => (0, 2),
+            Instruction::LocalGet32 => (0, 4),
+            Instruction::LocalGet64 => (0, 8),
+            Instruction::LocalSet8 => (1, 0),
+            Instruction::LocalSet16 => (2, 0),
+            Instruction::LocalSet32 => (4, 0),
+            Instruction::LocalSet64 => (8, 0),
+            Instruction::Drop8 => (1, 0),
+            Instruction::Drop16 => (2, 0),
+            Instruction::Drop32 => (4, 0),
+            Instruction::Drop64 => (8, 0),
+            Instruction::Const8 => (0, 1),
+            Instruction::Const16 => (0, 2),
+            Instruction::Const32 => (0, 4),
+            Instruction::Const64 => (0, 8),
+            Instruction::Dup8 => (1, 2),
+            Instruction::Dup16 => (2, 4),
+            Instruction::Dup32 => (4, 8),
+            Instruction::Dup64 => (8, 16),
+            Instruction::Swap8 => (2, 2),
+            Instruction::Swap16 => (4, 4),
+            Instruction::Swap32 => (8, 8),
+            Instruction::Swap64 => (16, 16),
+            Instruction::Over8 => (2, 3),
+            Instruction::Over16 => (4, 6),
+            Instruction::Over32 => (8, 12),
+            Instruction::Over64 => (16, 24),
+            Instruction::Rot8 => (3, 3),
+            Instruction::Rot16 => (6, 6),
+            Instruction::Rot32 => (12, 12),
+            Instruction::Rot64 => (24, 24),
+            Instruction::Pick8 => (1, 2),
+            Instruction::Pick16 => (2, 4),
+            Instruction::Pick32 => (4, 8),
+            Instruction::Pick64 => (8, 16),
+            Instruction::Extend8To16 => (1, 2),
+            Instruction::Extend8To32 => (1, 4),
+            Instruction::Extend16To32 => (2, 4),
+            Instruction::Extend8To64 => (1, 8),
+            Instruction::Extend16To64 => (2, 8),
+            Instruction::Extend32To64 => (4, 8),
+            Instruction::ExtendSign8To16 => (1, 2),
+            Instruction::ExtendSign8To32 => (1, 4),
+            Instruction::ExtendSign16To32 => (2, 4),
+            Instruction::ExtendSign8To64 => (1, 8),
+            Instruction::ExtendSign16To64 => (2, 8),
+            Instruction::ExtendSign32To64 => (4, 8),
+            Instruction::Trunc16To8 => (2, 1),
+            Instruction::Trunc32To8 => (4, 1),
+            Instruction::Trunc64To8 => (8, 1),
+            Instruction::Trunc64To16 => (8, 2),
+            Instruction::Trunc64To32 => (8, 4),
+            Instruction::Add8 => (2, 1),
+            Instruction::Add16 => (4, 2),
+            Instruction::Add32 => (8, 4),
+            Instruction::Add64 => (16, 8),
+            Instruction::Sub8 => (2, 1),
+            Instruction::Sub16 => (4, 2),
+            Instruction::Sub32 => (8, 4),
+            Instruction::Sub64 => (16, 8),
+            Instruction::Mul8 => (2, 1),
+            Instruction::Mul16 => (4, 2),
+            Instruction::Mul32 => (8, 4),
+            Instruction::Mul64 => (16, 8),
+            Instruction::Div8 => (2, 1),
+            Instruction::Div16 => (4, 2),
+            Instruction::Div32 => (8, 4),
+            Instruction::Div64 => (16, 8),
+            Instruction::DivSign8 => (2, 1),
+            Instruction::DivSign16 => (4, 2),
+            Instruction::DivSign32 => (8, 4),
+            Instruction::DivSign64 => (16, 8),
+            Instruction::Rem8 => (2, 1),
+            Instruction::Rem16 => (4, 2),
+            Instruction::Rem32 => (8, 4),
+            Instruction::Rem64 => (16, 8),
+            Instruction::RemSign8 => (2, 1),
+            Instruction::RemSign16 => (4, 2),
+            Instruction::RemSign32 => (8, 4),
+            Instruction::RemSign64 => (16, 8),
+            Instruction::And8 => (2, 1),
+            Instruction::And16 => (4, 2),
+            Instruction::And32 => (8, 4),
+            Instruction::And64 => (16, 8),
+            Instruction::Or8 => (2, 1),
+            Instruction::Or16 => (4, 2),
+            Instruction::Or32 => (8, 4),
+            Instruction::Or64 => (16, 8),
+            Instruction::Xor8 => (2, 1),
+            Instruction::Xor16 => (4, 2),
+            Instruction::Xor32 => (8, 4),
+            Instruction::Xor64 => (16, 8),
+            Instruction::Not8 => (1, 1),
+            Instruction::Not16 => (2, 2),
+            Instruction::Not32 => (4, 4),
+            Instruction::Not64 => (8, 8),
+            Instruction::Shl8 => (2, 1),
+            Instruction::Shl16 => (4, 2),
+            Instruction::Shl32 => (8, 4),
+            Instruction::Shl64 => (16, 8),
+            Instruction::Shr8 => (2, 1),
+            Instruction::Shr16 => (4, 2),
+            Instruction::Shr32 => (8, 4),
+            Instruction::Shr64 => (16, 8),
+            Instruction::ShrSign8 => (2, 1),
+            Instruction::ShrSign16 => (4, 2),
+            Instruction::ShrSign32 => (8, 4),
+            Instruction::ShrSign64 => (16, 8),
+            Instruction::Rotl8 => (2, 1),
+            Instruction::Rotl16 => (4, 2),
+            Instruction::Rotl32 => (8, 4),
+            Instruction::Rotl64 => (16, 8),
+            Instruction::Rotr8 => (2, 1),
+            Instruction::Rotr16 => (4, 2),
+            Instruction::Rotr32 => (8, 4),
+            Instruction::Rotr64 => (16, 8),
+            Instruction::Popcnt8 => (1, 1),
+            Instruction::Popcnt16 => (2, 2),
+            Instruction::Popcnt32 => (4, 4),
+            Instruction::Popcnt64 => (8, 8),
+            Instruction::Clz8 => (1, 1),
+            Instruction::Clz16 => (2, 2),
+            Instruction::Clz32 => (4, 4),
+            Instruction::Clz64 => (8, 8),
+            Instruction::Ctz8 => (1, 1),
+            Instruction::Ctz16 => (2, 2),
+            Instruction::Ctz32 => (4, 4),
+            Instruction::Ctz64 => (8, 8),
+            Instruction::Eq8 => (2, 1),
+            Instruction::Eq16 => (4, 1),
+            Instruction::Eq32 => (8, 1),
+            Instruction::Eq64 => (16, 1),
+            Instruction::Ne8 => (2, 1),
+            Instruction::Ne16 => (4, 1),
+            Instruction::Ne32 => (8, 1),
+            Instruction::Ne64 => (16, 1),
+            Instruction::Eqz8 => (1, 1),
+            Instruction::Eqz16 => (2, 1),
+            Instruction::Eqz32 => (4, 1),
+            Instruction::Eqz64 => (8, 1),
+            Instruction::Lt8 => (2, 1),
+            Instruction::Lt16 => (4, 1),
+            Instruction::Lt32 => (8, 1),
+            Instruction::Lt64 => (16, 1),
+            Instruction::LtSign8 => (2, 1),
+            Instruction::LtSign16 => (4, 1),
+            Instruction::LtSign32 => (8, 1),
+            Instruction::LtSign64 => (16, 1),
+            Instruction::Le8 => (2, 1),
+            Instruction::Le16 => (4, 1),
+            Instruction::Le32 => (8, 1),
+            Instruction::Le64 => (16, 1),
+            Instruction::LeSign8 => (2, 1),
+            Instruction::LeSign16 => (4, 1),
+            Instruction::LeSign32 => (8, 1),
+            Instruction::LeSign64 => (16, 1),
+            Instruction::Gt8 => (2, 1),
+            Instruction::Gt16 => (4, 1),
+            Instruction::Gt32 => (8, 1),
+            Instruction::Gt64 => (16, 1),
+            Instruction::GtSign8 => (2, 1),
+            Instruction::GtSign16 => (4, 1),
+            Instruction::GtSign32 => (8, 1),
+            Instruction::GtSign64 => (16, 1),
+            Instruction::Ge8 => (2, 1),
+            Instruction::Ge16 => (4, 1),
+            Instruction::Ge32 => (8, 1),
+            Instruction::Ge64 => (16, 1),
+            Instruction::GeSign8 => (2, 1),
+            Instruction::GeSign16 => (4, 1),
+            Instruction::GeSign32 => (8, 1),
+            Instruction::GeSign64 => (16, 1),
+            Instruction::EqF32 => (8, 1),
+            Instruction::EqF64 => (16, 1),
+            Instruction::NeF32 => (8, 1),
+            Instruction::NeF64 => (16, 1),
+            Instruction::LtF32 => (8, 1),
+            Instruction::LtF64 => (16, 1),
+            Instruction::LeF32 => (8, 1),
+            Instruction::LeF64 => (16, 1),
+            Instruction::GtF32 => (8, 1),
+            Instruction::GtF64 => (16, 1),
+            Instruction::GeF32 => (8, 1),
+            Instruction::GeF64 => (16, 1),
+            Instruction::AddF32 => (8, 4),
+            Instruction::AddF64 => (16, 8),
+            Instruction::SubF32 => (8, 4),
+            Instruction::SubF64 => (16, 8),
+            Instruction::MulF32 => (8, 4),
+            Instruction::MulF64 => (16, 8),
+            Instruction::DivF32 => (8, 4),
+            Instruction::DivF64 => (16, 8),
+            Instruction::NegF32 => (4, 4),
+            Instruction::NegF64 => (8, 8),
+            Instruction::AbsF32 => (4, 4),
+            Instruction::AbsF64 => (8, 8),
+            Instruction::SqrtF32 => (4, 4),
+            Instruction::SqrtF64 => (8, 8),
+            Instruction::MinF32 => (8, 4),
+            Instruction::MinF64 => (16, 8),
+            Instruction::MaxF32 => (8, 4),
+            Instruction::MaxF64 => (16, 8),
+            Instruction::FloorF32 => (4, 4),
+            Instruction::FloorF64 => (8, 8),
+            Instruction::CeilF32 => (4, 4),
+            Instruction::CeilF64 => (8, 8),
+            Instruction::TruncF32 => (4, 4),
+            Instruction::TruncF64 => (8, 8),
+            Instruction::NearestF32 => (4, 4),
+            Instruction::NearestF64 => (8, 8),
+            Instruction::CopysignF32 => (8, 4),
+            Instruction::CopysignF64 => (16, 8),
+            Instruction::Convert32ToF32 | Instruction::ConvertSign32ToF32 => (4, 4),
+            Instruction::Convert32ToF64 | Instruction::ConvertSign32ToF64 => (4, 8),
+            Instruction::Convert64ToF32 | Instruction::ConvertSign64ToF32 => (8, 4),
+            Instruction::Convert64ToF64 | Instruction::ConvertSign64ToF64 => (8, 8),
+            Instruction::TruncF32To32 | Instruction::TruncSignF32To32 => (4, 4),
+            Instruction::TruncF32To64 | Instruction::TruncSignF32To64 => (4, 8),
+            Instruction::TruncF64To32 | Instruction::TruncSignF64To32 => (8, 4),
+            Instruction::TruncF64To64 | Instruction::TruncSignF64To64 => (8, 8),
+            Instruction::TruncSatF32To32 | Instruction::TruncSatSignF32To32 => (4, 4),
+            Instruction::TruncSatF32To64 | Instruction::TruncSatSignF32To64 => (4, 8),
+            Instruction::TruncSatF64To32 | Instruction::TruncSatSignF64To32 => (8, 4),
+            Instruction::TruncSatF64To64 | Instruction::TruncSatSignF64To64 => (8, 8),
+            Instruction::PromoteF32ToF64 => (4, 8),
+            Instruction::DemoteF64ToF32 => (8, 4),
+            Instruction::ReinterpretF32To32 => (4, 4),
+            Instruction::ReinterpretF64To64 => (8, 8),
+            Instruction::Reinterpret32ToF32 => (4, 4),
+            Instruction::Reinterpret64ToF64 => (8, 8),
+            Instruction::BranchRel => (0, 0),
+            Instruction::BranchRelIf8 => (1, 0),
+            Instruction::BranchRelIf16 => (2, 0),
+            Instruction::BranchRelIf32 => (4, 0),
+            Instruction::BranchRelIf64 => (8, 0),
+            Instruction::CallRel => (0, 0),
+            Instruction::Select8 => (3, 1),
+            Instruction::Select16 => (5, 2),
+            Instruction::Select32 => (9, 4),
+            Instruction::Select64 => (17, 8),
+            Instruction::AddSign8 => (2, 1),
+            Instruction::AddSign16 => (4, 2),
+            Instruction::AddSign32 => (8, 4),
+            Instruction::AddSign64 => (16, 8),
+            Instruction::SubSign8 => (2, 1),
+            Instruction::SubSign16 => (4, 2),
+            Instruction::SubSign32 => (8, 4),
+            Instruction::SubSign64 => (16, 8),
+            Instruction::MulSign8 => (2, 1),
+            Instruction::MulSign16 => (4, 2),
+            Instruction::MulSign32 => (8, 4),
+            Instruction::MulSign64 => (16, 8),
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Writes the opcode of the instruction into `bytes`.
+    pub fn write_opcode(&self, bytes: &mut Vec<u8>) {
+        let index = self.index();
+        if index < INSTRUCTION_LIST.len() {
+            bytes.push(index as u8);
+        } else {
+            bytes.push(EXTENDED_OPCODE);
+            bytes.push((index - INSTRUCTION_LIST.len()) as u8);
+        }
     }
 }
 
 pub type InstructionFunction = fn(&mut Processor) -> Result<(), Action>;
 
+/// The opcode that prefixes the instructions of the extended set, whose indexes start at 256.
+pub const EXTENDED_OPCODE: u8 = 255;
+
 pub static INSTRUCTION_LIST: [InstructionFunction; 256] = [
     // General
     unreachable, // 0
@@ -98,10 +1375,10 @@ pub static INSTRUCTION_LIST: [InstructionFunction; 256] = [
     branch_if_16,
     branch_if_32,
     branch_if_64,
-    unreachable,
-    unreachable,
-    unreachable, // 10
-    unreachable,
+    call,
+    r#return,
+    branch_table, // 10
+    call_host,
     memory_size,
     memory_grow,
     memory_fill_8,
@@ -121,15 +1398,15 @@ pub static INSTRUCTION_LIST: [InstructionFunction; 256] = [
     program_data_load_16,
     program_data_load_32,
     program_data_load_64, // 30
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
+    local_get_8,
+    local_get_16,
+    local_get_32,
+    local_get_64,
+    local_set_8,
+    local_set_16,
+    local_set_32,
+    local_set_64,
+    invalid_opcode,
     drop_8, // 40
     drop_16,
     drop_32,
@@ -138,28 +1415,28 @@ pub static INSTRUCTION_LIST: [InstructionFunction; 256] = [
     const_16,
     const_32,
     const_64,
-    unreachable,
-    unreachable,
-    unreachable, // 50
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 60
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
+    dup_8,
+    dup_16,
+    dup_32, // 50
+    dup_64,
+    swap_8,
+    swap_16,
+    swap_32,
+    swap_64,
+    over_8,
+    over_16,
+    over_32,
+    over_64,
+    rot_8, // 60
+    rot_16,
+    rot_32,
+    rot_64,
+    pick_8,
+    pick_16,
+    pick_32,
+    pick_64,
+    invalid_opcode,
+    invalid_opcode,
     extend_8_to_16, // 70
     extend_8_to_32,
     extend_16_to_32,
@@ -177,173 +1454,432 @@ pub static INSTRUCTION_LIST: [InstructionFunction; 256] = [
     trunc_64_to_8,
     trunc_64_to_16,
     trunc_64_to_32,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 90
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 100
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 110
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 120
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 130
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 140
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 150
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 160
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 170
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 180
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 190
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 200
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 210
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 220
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 230
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 240
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable, // 250
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
-    unreachable,
+    invalid_opcode,
+    invalid_opcode,
+    invalid_opcode,
+    add_8, // 90
+    add_16,
+    add_32,
+    add_64,
+    sub_8,
+    sub_16,
+    sub_32,
+    sub_64,
+    mul_8,
+    mul_16,
+    mul_32, // 100
+    mul_64,
+    div_8,
+    div_16,
+    div_32,
+    div_64,
+    div_sign_8,
+    div_sign_16,
+    div_sign_32,
+    div_sign_64,
+    rem_8, // 110
+    rem_16,
+    rem_32,
+    rem_64,
+    rem_sign_8,
+    rem_sign_16,
+    rem_sign_32,
+    rem_sign_64,
+    invalid_opcode,
+    invalid_opcode,
+    and_8, // 120
+    and_16,
+    and_32,
+    and_64,
+    or_8,
+    or_16,
+    or_32,
+    or_64,
+    xor_8,
+    xor_16,
+    xor_32, // 130
+    xor_64,
+    not_8,
+    not_16,
+    not_32,
+    not_64,
+    shl_8,
+    shl_16,
+    shl_32,
+    shl_64,
+    shr_8, // 140
+    shr_16,
+    shr_32,
+    shr_64,
+    shr_sign_8,
+    shr_sign_16,
+    shr_sign_32,
+    shr_sign_64,
+    rotl_8,
+    rotl_16,
+    rotl_32, // 150
+    rotl_64,
+    rotr_8,
+    rotr_16,
+    rotr_32,
+    rotr_64,
+    popcnt_8,
+    popcnt_16,
+    popcnt_32,
+    popcnt_64,
+    clz_8, // 160
+    clz_16,
+    clz_32,
+    clz_64,
+    ctz_8,
+    ctz_16,
+    ctz_32,
+    ctz_64,
+    invalid_opcode,
+    invalid_opcode,
+    eq_8, // 170
+    eq_16,
+    eq_32,
+    eq_64,
+    ne_8,
+    ne_16,
+    ne_32,
+    ne_64,
+    eqz_8,
+    eqz_16,
+    eqz_32, // 180
+    eqz_64,
+    lt_8,
+    lt_16,
+    lt_32,
+    lt_64,
+    lt_sign_8,
+    lt_sign_16,
+    lt_sign_32,
+    lt_sign_64,
+    le_8, // 190
+    le_16,
+    le_32,
+    le_64,
+    le_sign_8,
+    le_sign_16,
+    le_sign_32,
+    le_sign_64,
+    gt_8,
+    gt_16,
+    gt_32, // 200
+    gt_64,
+    gt_sign_8,
+    gt_sign_16,
+    gt_sign_32,
+    gt_sign_64,
+    ge_8,
+    ge_16,
+    ge_32,
+    ge_64,
+    ge_sign_8, // 210
+    ge_sign_16,
+    ge_sign_32,
+    ge_sign_64,
+    eq_f32,
+    eq_f64,
+    ne_f32,
+    ne_f64,
+    lt_f32,
+    lt_f64,
+    le_f32, // 220
+    le_f64,
+    gt_f32,
+    gt_f64,
+    ge_f32,
+    ge_f64,
+    add_f32,
+    add_f64,
+    sub_f32,
+    sub_f64,
+    mul_f32, // 230
+    mul_f64,
+    div_f32,
+    div_f64,
+    neg_f32,
+    neg_f64,
+    abs_f32,
+    abs_f64,
+    sqrt_f32,
+    sqrt_f64,
+    min_f32, // 240
+    min_f64,
+    max_f32,
+    max_f64,
+    floor_f32,
+    floor_f64,
+    ceil_f32,
+    ceil_f64,
+    trunc_f32,
+    trunc_f64,
+    nearest_f32, // 250
+    nearest_f64,
+    copysign_f32,
+    copysign_f64,
+    invalid_opcode,
+    extended,
+];
+
+pub static EXTENDED_INSTRUCTION_LIST: [InstructionFunction; 256] = [
+    convert_32_to_f32, // 0
+    convert_sign_32_to_f32,
+    convert_32_to_f64,
+    convert_sign_32_to_f64,
+    convert_64_to_f32,
+    convert_sign_64_to_f32,
+    convert_64_to_f64,
+    convert_sign_64_to_f64,
+    trunc_f32_to_32,
+    trunc_sign_f32_to_32,
+    trunc_f32_to_64, // 10
+    trunc_sign_f32_to_64,
+    trunc_f64_to_32,
+    trunc_sign_f64_to_32,
+    trunc_f64_to_64,
+    trunc_sign_f64_to_64,
+    trunc_sat_f32_to_32,
+    trunc_sat_sign_f32_to_32,
+    trunc_sat_f32_to_64,
+    trunc_sat_sign_f32_to_64,
+    trunc_sat_f64_to_32, // 20
+    trunc_sat_sign_f64_to_32,
+    trunc_sat_f64_to_64,
+    trunc_sat_sign_f64_to_64,
+    promote_f32_to_f64,
+    demote_f64_to_f32,
+    reinterpret_f32_to_32,
+    reinterpret_f64_to_64,
+    reinterpret_32_to_f32,
+    reinterpret_64_to_f64,
+    branch_rel, // 30
+    branch_rel_if_8,
+    branch_rel_if_16,
+    branch_rel_if_32,
+    branch_rel_if_64,
+    call_rel,
+    select_8,
+    select_16,
+    select_32,
+    select_64,
+    add_sign_8, // 40
+    add_sign_16,
+    add_sign_32,
+    add_sign_64,
+    sub_sign_8,
+    sub_sign_16,
+    sub_sign_32,
+    sub_sign_64,
+    mul_sign_8,
+    mul_sign_16,
+    mul_sign_32, // 50
+    mul_sign_64,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 60
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 70
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 80
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 90
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 100
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 110
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 120
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 130
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 140
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 150
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 160
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 170
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 180
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 190
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 200
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 210
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 220
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 230
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 240
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode, // 250
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
+    invalid_extended_opcode,
 ];
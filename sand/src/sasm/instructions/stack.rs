@@ -1,4 +1,4 @@
-use crate::sasm::{Action, Processor};
+use crate::sasm::{Action, Processor, TrapKind};
 
 /// Drops a ?8 from the stack.
 ///
@@ -76,6 +76,414 @@ pub fn const_64(processor: &mut Processor) -> Result<(), Action> {
     Ok(())
 }
 
+/// Duplicates the ?8 value at the top of the stack.
+///
+/// Stack:
+/// - ?8 - Value.
+/// + ?8 - Value.
+/// + ?8 - Value.
+pub fn dup_8(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.peek_u8()?;
+    processor.push_u8(value)?;
+    Ok(())
+}
+
+/// Duplicates the ?16 value at the top of the stack.
+///
+/// Stack:
+/// - ?16 - Value.
+/// + ?16 - Value.
+/// + ?16 - Value.
+pub fn dup_16(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.peek_u16()?;
+    processor.push_u16(value)?;
+    Ok(())
+}
+
+/// Duplicates the ?32 value at the top of the stack.
+///
+/// Stack:
+/// - ?32 - Value.
+/// + ?32 - Value.
+/// + ?32 - Value.
+pub fn dup_32(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.peek_u32()?;
+    processor.push_u32(value)?;
+    Ok(())
+}
+
+/// Duplicates the ?64 value at the top of the stack.
+///
+/// Stack:
+/// - ?64 - Value.
+/// + ?64 - Value.
+/// + ?64 - Value.
+pub fn dup_64(processor: &mut Processor) -> Result<(), Action> {
+    let value = processor.peek_u64()?;
+    processor.push_u64(value)?;
+    Ok(())
+}
+
+/// Swaps the two ?8 values at the top of the stack.
+///
+/// Stack:
+/// - ?8 - Top value.
+/// - ?8 - Second value.
+/// + ?8 - Top value.
+/// + ?8 - Second value.
+pub fn swap_8(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u8()?;
+    let second = processor.pop_u8()?;
+    processor.push_u8(top)?;
+    processor.push_u8(second)?;
+    Ok(())
+}
+
+/// Swaps the two ?16 values at the top of the stack.
+///
+/// Stack:
+/// - ?16 - Top value.
+/// - ?16 - Second value.
+/// + ?16 - Top value.
+/// + ?16 - Second value.
+pub fn swap_16(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u16()?;
+    let second = processor.pop_u16()?;
+    processor.push_u16(top)?;
+    processor.push_u16(second)?;
+    Ok(())
+}
+
+/// Swaps the two ?32 values at the top of the stack.
+///
+/// Stack:
+/// - ?32 - Top value.
+/// - ?32 - Second value.
+/// + ?32 - Top value.
+/// + ?32 - Second value.
+pub fn swap_32(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u32()?;
+    let second = processor.pop_u32()?;
+    processor.push_u32(top)?;
+    processor.push_u32(second)?;
+    Ok(())
+}
+
+/// Swaps the two ?64 values at the top of the stack.
+///
+/// Stack:
+/// - ?64 - Top value.
+/// - ?64 - Second value.
+/// + ?64 - Top value.
+/// + ?64 - Second value.
+pub fn swap_64(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u64()?;
+    let second = processor.pop_u64()?;
+    processor.push_u64(top)?;
+    processor.push_u64(second)?;
+    Ok(())
+}
+
+/// Pushes a copy of the ?8 value placed below the top of the stack.
+///
+/// Stack:
+/// - ?8 - Top value.
+/// - ?8 - Second value.
+/// + ?8 - Second value.
+/// + ?8 - Top value.
+/// + ?8 - Second value.
+pub fn over_8(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u8()?;
+    let second = processor.peek_u8()?;
+    processor.push_u8(top)?;
+    processor.push_u8(second)?;
+    Ok(())
+}
+
+/// Pushes a copy of the ?16 value placed below the top of the stack.
+///
+/// Stack:
+/// - ?16 - Top value.
+/// - ?16 - Second value.
+/// + ?16 - Second value.
+/// + ?16 - Top value.
+/// + ?16 - Second value.
+pub fn over_16(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u16()?;
+    let second = processor.peek_u16()?;
+    processor.push_u16(top)?;
+    processor.push_u16(second)?;
+    Ok(())
+}
+
+/// Pushes a copy of the ?32 value placed below the top of the stack.
+///
+/// Stack:
+/// - ?32 - Top value.
+/// - ?32 - Second value.
+/// + ?32 - Second value.
+/// + ?32 - Top value.
+/// + ?32 - Second value.
+pub fn over_32(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u32()?;
+    let second = processor.peek_u32()?;
+    processor.push_u32(top)?;
+    processor.push_u32(second)?;
+    Ok(())
+}
+
+/// Pushes a copy of the ?64 value placed below the top of the stack.
+///
+/// Stack:
+/// - ?64 - Top value.
+/// - ?64 - Second value.
+/// + ?64 - Second value.
+/// + ?64 - Top value.
+/// + ?64 - Second value.
+pub fn over_64(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u64()?;
+    let second = processor.peek_u64()?;
+    processor.push_u64(top)?;
+    processor.push_u64(second)?;
+    Ok(())
+}
+
+/// Moves the third ?8 value of the stack to the top.
+///
+/// Stack:
+/// - ?8 - Top value.
+/// - ?8 - Second value.
+/// - ?8 - Third value.
+/// + ?8 - Second value.
+/// + ?8 - Top value.
+/// + ?8 - Third value.
+pub fn rot_8(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u8()?;
+    let second = processor.pop_u8()?;
+    let third = processor.pop_u8()?;
+    processor.push_u8(second)?;
+    processor.push_u8(top)?;
+    processor.push_u8(third)?;
+    Ok(())
+}
+
+/// Moves the third ?16 value of the stack to the top.
+///
+/// Stack:
+/// - ?16 - Top value.
+/// - ?16 - Second value.
+/// - ?16 - Third value.
+/// + ?16 - Second value.
+/// + ?16 - Top value.
+/// + ?16 - Third value.
+pub fn rot_16(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u16()?;
+    let second = processor.pop_u16()?;
+    let third = processor.pop_u16()?;
+    processor.push_u16(second)?;
+    processor.push_u16(top)?;
+    processor.push_u16(third)?;
+    Ok(())
+}
+
+/// Moves the third ?32 value of the stack to the top.
+///
+/// Stack:
+/// - ?32 - Top value.
+/// - ?32 - Second value.
+/// - ?32 - Third value.
+/// + ?32 - Second value.
+/// + ?32 - Top value.
+/// + ?32 - Third value.
+pub fn rot_32(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u32()?;
+    let second = processor.pop_u32()?;
+    let third = processor.pop_u32()?;
+    processor.push_u32(second)?;
+    processor.push_u32(top)?;
+    processor.push_u32(third)?;
+    Ok(())
+}
+
+/// Moves the third ?64 value of the stack to the top.
+///
+/// Stack:
+/// - ?64 - Top value.
+/// - ?64 - Second value.
+/// - ?64 - Third value.
+/// + ?64 - Second value.
+/// + ?64 - Top value.
+/// + ?64 - Third value.
+pub fn rot_64(processor: &mut Processor) -> Result<(), Action> {
+    let top = processor.pop_u64()?;
+    let second = processor.pop_u64()?;
+    let third = processor.pop_u64()?;
+    processor.push_u64(second)?;
+    processor.push_u64(top)?;
+    processor.push_u64(third)?;
+    Ok(())
+}
+
+/// Reads the next u8 value from the code as an index and pushes a copy of the ?8 value
+/// placed at that position of the stack, counting from the top value that is 0.
+/// Can cause a trap when the stack does not contain enough values.
+///
+/// Stack:
+/// + ?8 - Copied value.
+pub fn pick_8(processor: &mut Processor) -> Result<(), Action> {
+    let index = processor.code_next_u8()? as usize;
+    let distance = (index + 1) * std::mem::size_of::<u8>();
+    if distance > processor.stack_pointer() {
+        return Err(Action::trap(TrapKind::StackUnderflow));
+    }
+
+    let position = processor.stack_pointer() - distance;
+    let value = processor.memory().read_u8_at(position)?;
+    processor.push_u8(value)?;
+    Ok(())
+}
+
+/// Reads the next u8 value from the code as an index and pushes a copy of the ?16 value
+/// placed at that position of the stack, counting from the top value that is 0.
+/// Can cause a trap when the stack does not contain enough values.
+///
+/// Stack:
+/// + ?16 - Copied value.
+pub fn pick_16(processor: &mut Processor) -> Result<(), Action> {
+    let index = processor.code_next_u8()? as usize;
+    let distance = (index + 1) * std::mem::size_of::<u16>();
+    if distance > processor.stack_pointer() {
+        return Err(Action::trap(TrapKind::StackUnderflow));
+    }
+
+    let position = processor.stack_pointer() - distance;
+    let value = processor.memory().read_u16_at(position)?;
+    processor.push_u16(value)?;
+    Ok(())
+}
+
+/// Reads the next u8 value from the code as an index and pushes a copy of the ?32 value
+/// placed at that position of the stack, counting from the top value that is 0.
+/// Can cause a trap when the stack does not contain enough values.
+///
+/// Stack:
+/// + ?32 - Copied value.
+pub fn pick_32(processor: &mut Processor) -> Result<(), Action> {
+    let index = processor.code_next_u8()? as usize;
+    let distance = (index + 1) * std::mem::size_of::<u32>();
+    if distance > processor.stack_pointer() {
+        return Err(Action::trap(TrapKind::StackUnderflow));
+    }
+
+    let position = processor.stack_pointer() - distance;
+    let value = processor.memory().read_u32_at(position)?;
+    processor.push_u32(value)?;
+    Ok(())
+}
+
+/// Reads the next u8 value from the code as an index and pushes a copy of the ?64 value
+/// placed at that position of the stack, counting from the top value that is 0.
+/// Can cause a trap when the stack does not contain enough values.
+///
+/// Stack:
+/// + ?64 - Copied value.
+pub fn pick_64(processor: &mut Processor) -> Result<(), Action> {
+    let index = processor.code_next_u8()? as usize;
+    let distance = (index + 1) * std::mem::size_of::<u64>();
+    if distance > processor.stack_pointer() {
+        return Err(Action::trap(TrapKind::StackUnderflow));
+    }
+
+    let position = processor.stack_pointer() - distance;
+    let value = processor.memory().read_u64_at(position)?;
+    processor.push_u64(value)?;
+    Ok(())
+}
+
+/// Pops a u8 value from the stack that acts as a condition and two ?8 values, and pushes
+/// the first one if the condition is not zero or the second one otherwise.
+///
+/// Stack:
+/// - u8 - Condition.
+/// - ?8 - Value for false.
+/// - ?8 - Value for true.
+/// + ?8 - Selected value.
+pub fn select_8(processor: &mut Processor) -> Result<(), Action> {
+    let condition = processor.pop_u8()?;
+    let if_false = processor.pop_u8()?;
+    let if_true = processor.pop_u8()?;
+
+    if condition != 0 {
+        processor.push_u8(if_true)?;
+    } else {
+        processor.push_u8(if_false)?;
+    }
+    Ok(())
+}
+
+/// Pops a u8 value from the stack that acts as a condition and two ?16 values, and pushes
+/// the first one if the condition is not zero or the second one otherwise.
+///
+/// Stack:
+/// - u8 - Condition.
+/// - ?16 - Value for false.
+/// - ?16 - Value for true.
+/// + ?16 - Selected value.
+pub fn select_16(processor: &mut Processor) -> Result<(), Action> {
+    let condition = processor.pop_u8()?;
+    let if_false = processor.pop_u16()?;
+    let if_true = processor.pop_u16()?;
+
+    if condition != 0 {
+        processor.push_u16(if_true)?;
+    } else {
+        processor.push_u16(if_false)?;
+    }
+    Ok(())
+}
+
+/// Pops a u8 value from the stack that acts as a condition and two ?32 values, and pushes
+/// the first one if the condition is not zero or the second one otherwise.
+///
+/// Stack:
+/// - u8 - Condition.
+/// - ?32 - Value for false.
+/// - ?32 - Value for true.
+/// + ?32 - Selected value.
+pub fn select_32(processor: &mut Processor) -> Result<(), Action> {
+    let condition = processor.pop_u8()?;
+    let if_false = processor.pop_u32()?;
+    let if_true = processor.pop_u32()?;
+
+    if condition != 0 {
+        processor.push_u32(if_true)?;
+    } else {
+        processor.push_u32(if_false)?;
+    }
+    Ok(())
+}
+
+/// Pops a u8 value from the stack that acts as a condition and two ?64 values, and pushes
+/// the first one if the condition is not zero or the second one otherwise.
+///
+/// Stack:
+/// - u8 - Condition.
+/// - ?64 - Value for false.
+/// - ?64 - Value for true.
+/// + ?64 - Selected value.
+pub fn select_64(processor: &mut Processor) -> Result<(), Action> {
+    let condition = processor.pop_u8()?;
+    let if_false = processor.pop_u64()?;
+    let if_true = processor.pop_u64()?;
+
+    if condition != 0 {
+        processor.push_u64(if_true)?;
+    } else {
+        processor.push_u64(if_false)?;
+    }
+    Ok(())
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -224,4 +632,121 @@ mod test {
             "[4] The value stored in the stack is incorrect"
         );
     }
+
+    #[test]
+    fn test_dup_swap_over() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.push_u16(0x1234).unwrap();
+        dup_16(&mut processor).expect("[1] The dup must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x1234_1234,
+            "[1] The stack is incorrect"
+        );
+
+        // Case 2
+        processor.push_u8(0x01).unwrap();
+        processor.push_u8(0x02).unwrap();
+        swap_8(&mut processor).expect("[2] The swap must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x0102,
+            "[2] The stack is incorrect"
+        );
+
+        // Case 3
+        processor.push_u32(0x0a).unwrap();
+        processor.push_u32(0x0b).unwrap();
+        over_32(&mut processor).expect("[3] The over must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x0a,
+            "[3] The top is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x0b,
+            "[3] The second is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x0a,
+            "[3] The third is incorrect"
+        );
+
+        // Case 4
+        let error = swap_64(&mut processor).expect_err("[4] The swap must fail");
+        assert!(error.is_trap(), "[4] The error is incorrect");
+    }
+
+    #[test]
+    fn test_rot_pick() {
+        let program = Program::new_for_tests(vec![0x02, 0x03], 0, 0);
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.push_u8(0x01).unwrap();
+        processor.push_u8(0x02).unwrap();
+        processor.push_u8(0x03).unwrap();
+        rot_8(&mut processor).expect("[1] The rot must succeed");
+
+        // Case 2
+        pick_8(&mut processor).expect("[2] The pick must succeed");
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x02,
+            "[2] The value is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x01,
+            "[2] The rotated top is incorrect"
+        );
+        assert_eq!(
+            processor.pop_u8().unwrap(),
+            0x03,
+            "[2] The rotated second is incorrect"
+        );
+
+        // Case 3
+        let error = pick_8(&mut processor).expect_err("[3] The pick must fail");
+        assert_eq!(
+            error.unwrap_trap().kind,
+            TrapKind::StackUnderflow,
+            "[3] The error is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let program = Program::new_for_tests(Vec::new(), 0, 0);
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.push_u32(0x0a).unwrap();
+        processor.push_u32(0x0b).unwrap();
+        processor.push_u8(1).unwrap();
+        select_32(&mut processor).expect("[1] The select must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            0x0a,
+            "[1] The value is incorrect"
+        );
+        assert!(processor.is_stack_empty(), "[1] The stack must be empty");
+
+        // Case 2
+        processor.push_u16(0x0a).unwrap();
+        processor.push_u16(0x0b).unwrap();
+        processor.push_u8(0).unwrap();
+        select_16(&mut processor).expect("[2] The select must succeed");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x0b,
+            "[2] The value is incorrect"
+        );
+        assert!(processor.is_stack_empty(), "[2] The stack must be empty");
+    }
 }
@@ -1,6 +1,9 @@
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
 use num_integer::Integer;
 
-use crate::sasm::Action;
+use crate::sasm::{Action, TrapKind, Watchpoint, WatchpointHit};
 
 /// The default memory page size: 64KiB
 pub const MEMORY_DEFAULT_PAGE_SIZE: usize = 64 * 1024;
@@ -8,11 +11,16 @@ pub const MEMORY_DEFAULT_PAGE_SIZE: usize = 64 * 1024;
 /// The default stack size: 2MiB
 pub const MEMORY_DEFAULT_STACK_SIZE: usize = 2 * 1024 * 1024;
 
-/// A paginated memory abstraction.
+/// A paginated memory abstraction. The pages are materialized on their first write,
+/// so the unwritten ones read as zero and do not use any space.
 pub struct Memory {
     page_size: usize,
     max_pages: usize,
-    pub pages: Vec<Vec<u8>>,
+    page_count: usize,
+    /// The materialized pages by their index.
+    pages: BTreeMap<usize, Vec<u8>>,
+    watchpoints: Vec<Watchpoint>,
+    watchpoint_hit: Cell<Option<WatchpointHit>>,
 }
 
 impl Memory {
@@ -41,7 +49,10 @@ impl Memory {
         Memory {
             page_size,
             max_pages,
-            pages,
+            page_count,
+            pages: pages.into_iter().enumerate().collect(),
+            watchpoints: Vec::new(),
+            watchpoint_hit: Cell::new(None),
         }
     }
 
@@ -51,7 +62,10 @@ impl Memory {
         Memory {
             page_size,
             max_pages,
-            pages: Vec::new(),
+            page_count: 0,
+            pages: BTreeMap::new(),
+            watchpoints: Vec::new(),
+            watchpoint_hit: Cell::new(None),
         }
     }
 
@@ -69,20 +83,50 @@ impl Memory {
 
     #[inline]
     pub fn pages(&self) -> usize {
-        self.pages.len()
+        self.page_count
     }
 
     #[inline]
     pub fn size(&self) -> usize {
-        self.pages.len() * self.page_size
+        self.page_count * self.page_size
+    }
+
+    /// Gets the content of the page at `index` if it has been materialized.
+    #[inline]
+    pub fn page(&self, index: usize) -> Option<&[u8]> {
+        self.pages.get(&index).map(|v| v.as_slice())
+    }
+
+    /// Gets the materialized pages sorted by their index.
+    pub fn materialized_pages(&self) -> impl Iterator<Item = (usize, &[u8])> {
+        self.pages
+            .iter()
+            .map(|(index, page)| (*index, page.as_slice()))
+    }
+
+    #[inline]
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        &self.watchpoints
     }
 
     // METHODS ----------------------------------------------------------------
 
     pub fn read_at(&self, index: usize, bytes: &mut [u8]) -> Result<(), Action> {
+        self.inspect_at(index, bytes)?;
+        self.check_watchpoints(index, bytes.len(), false);
+
+        Ok(())
+    }
+
+    /// Reads like `read_at` but without matching the watchpoints, so that the tools that
+    /// inspect the memory do not interfere with them.
+    pub fn inspect_at(&self, index: usize, bytes: &mut [u8]) -> Result<(), Action> {
         let num_bytes = bytes.len();
         if index + num_bytes > self.size() {
-            return Err(Action::Panic("Segmentation Fault"));
+            return Err(Action::trap(TrapKind::MemoryOutOfBounds {
+                address: index,
+                size: num_bytes,
+            }));
         }
 
         let (mut page_index, mut index_in_page) = index.div_rem(&self.page_size);
@@ -113,9 +157,17 @@ impl Memory {
     }
 
     fn read_at_single_page(&self, page_index: usize, index_in_page: usize, bytes: &mut [u8]) {
-        let page = &self.pages[page_index];
-        let page_range = index_in_page..index_in_page + bytes.len();
-        bytes[..].clone_from_slice(&page[page_range]);
+        match self.pages.get(&page_index) {
+            Some(page) => {
+                let page_range = index_in_page..index_in_page + bytes.len();
+                bytes[..].clone_from_slice(&page[page_range]);
+            }
+            None => {
+                for byte in bytes {
+                    *byte = 0;
+                }
+            }
+        }
     }
 
     #[inline]
@@ -191,9 +243,14 @@ impl Memory {
     pub fn write_at(&mut self, index: usize, bytes: &[u8]) -> Result<(), Action> {
         let num_bytes = bytes.len();
         if index + num_bytes > self.size() {
-            return Err(Action::Panic("Segmentation Fault"));
+            return Err(Action::trap(TrapKind::MemoryOutOfBounds {
+                address: index,
+                size: num_bytes,
+            }));
         }
 
+        self.check_watchpoints(index, num_bytes, true);
+
         let (mut page_index, mut index_in_page) = index.div_rem(&self.page_size);
         let mut index_in_bytes = 0;
         loop {
@@ -222,7 +279,11 @@ impl Memory {
     }
 
     fn write_at_single_page(&mut self, page_index: usize, index_in_page: usize, bytes: &[u8]) {
-        let page = &mut self.pages[page_index];
+        let page_size = self.page_size;
+        let page = self
+            .pages
+            .entry(page_index)
+            .or_insert_with(|| vec![0; page_size]);
         page[index_in_page..index_in_page + bytes.len()].clone_from_slice(&bytes);
     }
 
@@ -288,10 +349,11 @@ impl Memory {
 
         let new_pages = self.pages() + 1;
         if new_pages > self.max_pages {
-            return Err(Action::Panic("Memory out of bounds"));
+            return Err(Action::trap(TrapKind::MemoryLimitExceeded));
         }
 
-        self.pages.push(page);
+        self.pages.insert(self.page_count, page);
+        self.page_count = new_pages;
 
         Ok(())
     }
@@ -301,21 +363,62 @@ impl Memory {
         self.add_empty_pages(1)
     }
 
+    /// Adds `amount` pages that are materialized on their first write.
     pub fn add_empty_pages(&mut self, amount: usize) -> Result<(), Action> {
-        let new_pages = self.pages() + amount;
-        if new_pages > self.max_pages {
-            return Err(Action::Panic("Memory out of bounds"));
-        }
+        let new_pages = match self.pages().checked_add(amount) {
+            Some(v) if v <= self.max_pages && v.checked_mul(self.page_size).is_some() => v,
+            _ => return Err(Action::trap(TrapKind::MemoryLimitExceeded)),
+        };
 
-        let page_size = self.page_size;
-        self.pages.resize_with(new_pages, || {
-            let mut page = Vec::with_capacity(page_size);
-            unsafe { page.set_len(page_size) }
-            page
-        });
+        self.page_count = new_pages;
 
         Ok(())
     }
+
+    /// Adds a watchpoint that records the first access to the memory that matches it.
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.watchpoints.push(watchpoint);
+    }
+
+    /// Removes a watchpoint and returns whether it existed.
+    pub fn remove_watchpoint(&mut self, watchpoint: &Watchpoint) -> bool {
+        match self.watchpoints.iter().position(|v| v == watchpoint) {
+            Some(index) => {
+                self.watchpoints.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gets the first access that matched a watchpoint since the last call to this method.
+    pub fn take_watchpoint_hit(&self) -> Option<WatchpointHit> {
+        self.watchpoint_hit.take()
+    }
+
+    fn check_watchpoints(&self, index: usize, num_bytes: usize, is_write: bool) {
+        if self.watchpoints.is_empty() {
+            return;
+        }
+
+        let hit = self.watchpoint_hit.get();
+        if hit.is_some() {
+            return;
+        }
+
+        let watchpoint = self
+            .watchpoints
+            .iter()
+            .find(|v| v.matches(index, num_bytes, is_write));
+        if let Some(watchpoint) = watchpoint {
+            self.watchpoint_hit.set(Some(WatchpointHit {
+                watchpoint: *watchpoint,
+                address: index,
+                size: num_bytes,
+                is_write,
+            }));
+        }
+    }
 }
 
 impl Default for Memory {
@@ -348,17 +451,60 @@ mod test {
         let result = memory
             .add_empty_page()
             .expect_err("[1] The addition of another empty page must fail");
-        assert_eq!(result.unwrap_panic(), "Memory out of bounds");
+        assert_eq!(result.unwrap_trap().kind, TrapKind::MemoryLimitExceeded);
 
         let result = memory
             .add_empty_pages(5)
             .expect_err("[1] The addition of other empty pages must fail");
-        assert_eq!(result.unwrap_panic(), "Memory out of bounds");
+        assert_eq!(result.unwrap_trap().kind, TrapKind::MemoryLimitExceeded);
 
         let result = memory
             .add_empty_page()
             .expect_err("[1] The addition of another custom page must fail");
-        assert_eq!(result.unwrap_panic(), "Memory out of bounds");
+        assert_eq!(result.unwrap_trap().kind, TrapKind::MemoryLimitExceeded);
+    }
+
+    #[test]
+    fn test_memory_sparse_pages() {
+        let mut memory = Memory::new_empty(MEMORY_DEFAULT_PAGE_SIZE, usize::MAX);
+        memory
+            .add_empty_pages(1 << 20)
+            .expect("[1] Cannot add many empty pages");
+        assert_eq!(
+            memory.materialized_pages().count(),
+            0,
+            "[1] No page must be materialized"
+        );
+
+        // Case 1: read unwritten pages.
+        let index = memory.size() - 2 * MEMORY_DEFAULT_PAGE_SIZE - 2;
+        let result = memory
+            .read_u32_at(index)
+            .expect("[1] The read must succeed");
+        assert_eq!(result, 0, "[1] The value is incorrect");
+
+        // Case 2: write between two pages.
+        memory
+            .write_u32_at(index, 0x12345678)
+            .expect("[2] The write must succeed");
+        let result = memory
+            .read_u32_at(index)
+            .expect("[2] The read must succeed");
+        assert_eq!(result, 0x12345678, "[2] The value is incorrect");
+        assert_eq!(
+            memory
+                .materialized_pages()
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>(),
+            vec![(1 << 20) - 3, (1 << 20) - 2],
+            "[2] The materialized pages are incorrect"
+        );
+
+        // Case 3: exceed the addressable size.
+        let result = memory
+            .add_empty_pages(usize::MAX)
+            .expect_err("[3] The addition must fail");
+        assert_eq!(result.unwrap_trap().kind, TrapKind::MemoryLimitExceeded);
     }
 
     #[test]
@@ -383,7 +529,13 @@ mod test {
         let result = memory
             .read_at(5, &mut bytes)
             .expect_err("[1] The read must fail");
-        assert_eq!(result.unwrap_panic(), "Segmentation Fault");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::MemoryOutOfBounds {
+                address: 5,
+                size: 1
+            }
+        );
 
         // Case 2: read many bytes.
         let mut bytes = [0; 3];
@@ -412,14 +564,24 @@ mod test {
             memory
                 .write_at(i, &mut bytes)
                 .expect(format!("[1] Cannot write byte at index {}", i).as_str());
-            assert_eq!(memory.pages[i][0], (i + 1) as u8, "The value is incorrect");
+            assert_eq!(
+                memory.page(i).unwrap()[0],
+                (i + 1) as u8,
+                "The value is incorrect"
+            );
         }
 
         let mut bytes = [5; 1];
         let result = memory
             .write_at(5, &mut bytes)
             .expect_err("[1] The write must fail");
-        assert_eq!(result.unwrap_panic(), "Segmentation Fault");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::MemoryOutOfBounds {
+                address: 5,
+                size: 1
+            }
+        );
 
         // Case 2: write many bytes.
         let mut memory = Memory::new_empty(1, max_pages);
@@ -433,11 +595,31 @@ mod test {
         memory
             .write_at(1, &mut bytes)
             .expect(format!("[2] Cannot read many bytes").as_str());
-        assert_eq!(memory.pages[0][0], 1, "[2] The value[0] is incorrect");
-        assert_eq!(memory.pages[1][0], 0, "[2] The value[1] is incorrect");
-        assert_eq!(memory.pages[2][0], 0, "[2] The value[2] is incorrect");
-        assert_eq!(memory.pages[3][0], 0, "[2] The value[3] is incorrect");
-        assert_eq!(memory.pages[4][0], 5, "[2] The value[4] is incorrect");
+        assert_eq!(
+            memory.page(0).unwrap()[0],
+            1,
+            "[2] The value[0] is incorrect"
+        );
+        assert_eq!(
+            memory.page(1).unwrap()[0],
+            0,
+            "[2] The value[1] is incorrect"
+        );
+        assert_eq!(
+            memory.page(2).unwrap()[0],
+            0,
+            "[2] The value[2] is incorrect"
+        );
+        assert_eq!(
+            memory.page(3).unwrap()[0],
+            0,
+            "[2] The value[3] is incorrect"
+        );
+        assert_eq!(
+            memory.page(4).unwrap()[0],
+            5,
+            "[2] The value[4] is incorrect"
+        );
     }
 
     #[test]
@@ -1,10 +1,32 @@
 pub use action::*;
+pub use call_frame::*;
+pub use debugger::*;
+pub use fuel::*;
+pub use host::*;
 pub use memory::*;
 pub use processor::*;
+pub use profiler::*;
 pub use program::*;
+pub use snapshot::*;
+pub use tracer::*;
+pub use trap::*;
+pub use verifier::*;
+pub use watchpoint::*;
 
 mod action;
+pub mod asm;
+mod call_frame;
+mod debugger;
+pub mod disasm;
+mod fuel;
+mod host;
 pub mod instructions;
 mod memory;
 mod processor;
+mod profiler;
 mod program;
+mod snapshot;
+mod tracer;
+mod trap;
+mod verifier;
+mod watchpoint;
@@ -1,4 +1,14 @@
-use crate::sasm::{Action, Memory, Program, MEMORY_DEFAULT_PAGE_SIZE};
+use std::sync::TryLockError;
+
+use crate::sasm::instructions::{Instruction, INSTRUCTION_LIST};
+use crate::sasm::{
+    Action, CallFrame, FuelCostTable, HostFunctions, Memory, Program, SharedTracer, SnapshotError,
+    SnapshotReader, SnapshotWriter, Tracer, Trap, TrapKind, FUEL_DEFAULT_COST,
+    MEMORY_DEFAULT_PAGE_SIZE,
+};
+
+/// The maximum number of nested calls a processor allows by default.
+pub const PROCESSOR_DEFAULT_MAX_CALL_DEPTH: usize = 1024;
 
 /// A VM processor that carries with memory, registers, etc.
 pub struct Processor {
@@ -7,7 +17,14 @@ pub struct Processor {
     program_counter: usize,
     stack_pointer: usize,
     stack_size: usize,
+    frame_pointer: usize,
     overflow_flag: bool,
+    call_stack: Vec<CallFrame>,
+    max_call_depth: usize,
+    host_functions: HostFunctions,
+    tracer: Option<SharedTracer>,
+    fuel: Option<u64>,
+    fuel_costs: FuelCostTable,
 }
 
 impl Processor {
@@ -21,13 +38,22 @@ impl Processor {
             memory.size()
         );
 
+        let program_counter = program.code_pointer();
+
         Processor {
             memory,
             program,
-            program_counter: 0,
+            program_counter,
             stack_pointer: 0,
             stack_size,
+            frame_pointer: 0,
             overflow_flag: false,
+            call_stack: Vec::new(),
+            max_call_depth: PROCESSOR_DEFAULT_MAX_CALL_DEPTH,
+            host_functions: HostFunctions::new(),
+            tracer: None,
+            fuel: None,
+            fuel_costs: FuelCostTable::new(),
         }
     }
 
@@ -40,16 +66,120 @@ impl Processor {
         let mut memory = Memory::new_empty(MEMORY_DEFAULT_PAGE_SIZE, usize::MAX);
         memory.add_empty_pages(stack_pages).unwrap();
 
+        let program_counter = program.code_pointer();
+
         Processor {
             memory,
             program,
-            program_counter: 0,
+            program_counter,
             stack_pointer: 0,
             stack_size,
+            frame_pointer: 0,
             overflow_flag: false,
+            call_stack: Vec::new(),
+            max_call_depth: PROCESSOR_DEFAULT_MAX_CALL_DEPTH,
+            host_functions: HostFunctions::new(),
+            tracer: None,
+            fuel: None,
+            fuel_costs: FuelCostTable::new(),
         }
     }
 
+    /// Restores a processor from a snapshot written by `to_snapshot`. The host functions,
+    /// the tracer and the fuel costs are not part of the snapshot so they must be set again.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Processor, SnapshotError> {
+        let mut reader = SnapshotReader::new(bytes)?;
+
+        let program_counter = reader.read_usize()?;
+        let stack_pointer = reader.read_usize()?;
+        let stack_size = reader.read_usize()?;
+        let frame_pointer = reader.read_usize()?;
+        let overflow_flag = reader.read_bool()?;
+        let max_call_depth = reader.read_usize()?;
+
+        let call_depth = reader.read_usize()?;
+        let mut call_stack = Vec::new();
+        for _ in 0..call_depth {
+            call_stack.push(CallFrame {
+                return_address: reader.read_usize()?,
+                stack_pointer: reader.read_usize()?,
+                frame_pointer: reader.read_usize()?,
+            });
+        }
+
+        let fuel = if reader.read_bool()? {
+            Some(reader.read_u64()?)
+        } else {
+            None
+        };
+
+        let page_size = reader.read_usize()?;
+        let max_pages = reader.read_usize()?;
+        let page_count = reader.read_usize()?;
+        let stored_page_count = reader.read_usize()?;
+        if page_size == 0 {
+            return Err(SnapshotError::InvalidState);
+        }
+
+        let mut memory = Memory::new_empty(page_size, max_pages);
+        if memory.add_empty_pages(page_count).is_err() || memory.size() < stack_size {
+            return Err(SnapshotError::InvalidState);
+        }
+
+        let mut next_index = 0;
+        for _ in 0..stored_page_count {
+            let index = reader.read_usize()?;
+            let page = reader.read_bytes(page_size)?;
+            if index < next_index || index >= page_count {
+                return Err(SnapshotError::InvalidState);
+            }
+
+            memory.write_at(index * page_size, page).unwrap();
+            next_index = index + 1;
+        }
+
+        let program_size = reader.read_usize()?;
+        let program = Program::from_bytes(reader.read_bytes(program_size)?)
+            .map_err(SnapshotError::InvalidProgram)?;
+        reader.finish()?;
+
+        let is_code_position = |position: usize| {
+            position >= program.code_pointer() && position <= program.code_pointer_end()
+        };
+        if !is_code_position(program_counter)
+            || stack_pointer > stack_size
+            || frame_pointer > stack_pointer
+            || call_stack.len() > max_call_depth
+        {
+            return Err(SnapshotError::InvalidState);
+        }
+
+        for frame in &call_stack {
+            if !is_code_position(frame.return_address)
+                || frame.stack_pointer > stack_pointer
+                || frame.frame_pointer > stack_pointer
+            {
+                return Err(SnapshotError::InvalidState);
+            }
+        }
+
+        Ok(Processor {
+            memory,
+            program,
+            program_counter,
+            stack_pointer,
+            stack_size,
+            frame_pointer,
+            overflow_flag,
+            call_stack,
+            max_call_depth,
+            host_functions: HostFunctions::new(),
+            tracer: None,
+            fuel,
+            fuel_costs: FuelCostTable::new(),
+        })
+    }
+
     // GETTERS ----------------------------------------------------------------
 
     #[inline]
@@ -79,7 +209,13 @@ impl Processor {
 
     #[inline]
     pub fn stack_size(&self) -> usize {
-        self.stack_pointer
+        self.stack_size
+    }
+
+    /// The position of the stack where the frame of the current subroutine starts.
+    #[inline]
+    pub fn frame_pointer(&self) -> usize {
+        self.frame_pointer
     }
 
     #[inline]
@@ -97,12 +233,63 @@ impl Processor {
         self.overflow_flag
     }
 
+    #[inline]
+    pub fn tracer(&self) -> Option<&SharedTracer> {
+        self.tracer.as_ref()
+    }
+
+    /// The fuel left to execute instructions or `None` if the execution is unbounded.
+    #[inline]
+    pub fn fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    #[inline]
+    pub fn fuel_costs(&self) -> &FuelCostTable {
+        &self.fuel_costs
+    }
+
+    #[inline]
+    pub fn fuel_costs_mut(&mut self) -> &mut FuelCostTable {
+        &mut self.fuel_costs
+    }
+
+    /// The frames of the subroutines being executed, the innermost at the end.
+    #[inline]
+    pub fn call_stack(&self) -> &[CallFrame] {
+        &self.call_stack
+    }
+
+    #[inline]
+    pub fn call_depth(&self) -> usize {
+        self.call_stack.len()
+    }
+
+    #[inline]
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    #[inline]
+    pub fn host_functions(&self) -> &HostFunctions {
+        &self.host_functions
+    }
+
+    #[inline]
+    pub fn host_functions_mut(&mut self) -> &mut HostFunctions {
+        &mut self.host_functions
+    }
+
     // SETTERS ----------------------------------------------------------------
 
     #[inline]
     pub fn set_program_counter(&mut self, program_counter: usize) -> Result<(), Action> {
-        if program_counter >= self.program().code_pointer_end() {
-            return Err(Action::Panic("Code Segmentation Fault"));
+        let program = self.program();
+        if program_counter < program.code_pointer() || program_counter >= program.code_pointer_end()
+        {
+            return Err(Action::trap(TrapKind::CodeOutOfBounds {
+                address: program_counter,
+            }));
         }
 
         self.program_counter = program_counter;
@@ -111,7 +298,7 @@ impl Processor {
 
     pub fn set_stack_pointer(&mut self, stack_pointer: usize) -> Result<(), Action> {
         if stack_pointer >= self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.stack_pointer = stack_pointer;
@@ -123,8 +310,94 @@ impl Processor {
         self.overflow_flag = overflow_flag
     }
 
+    #[inline]
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) {
+        self.max_call_depth = max_call_depth
+    }
+
+    /// Installs a tracer that observes every executed instruction, or removes it with `None`.
+    #[inline]
+    pub fn set_tracer(&mut self, tracer: Option<SharedTracer>) {
+        self.tracer = tracer
+    }
+
+    /// Sets the fuel budget that bounds the execution, or removes it with `None`.
+    #[inline]
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// Writes the state of the processor as a compact binary snapshot that `from_snapshot`
+    /// restores. The pages of the memory that are not materialized or only contain zeros
+    /// are not stored.
+    ///
+    /// The format of a snapshot is (all numbers in little-endian):
+    /// - [u8; 4] - Magic bytes: `SNAPSHOT_MAGIC`.
+    /// - u16 - Format version: `SNAPSHOT_FORMAT_VERSION`.
+    /// - u64 - Program counter, stack pointer, stack size and frame pointer.
+    /// - u8  - Overflow flag.
+    /// - u64 - Maximum call depth.
+    /// - u64 - Number of call frames followed by each frame:
+    ///   - u64 - Return address, stack pointer and frame pointer.
+    /// - u8  - Whether the fuel is bounded, followed by the fuel left as u64 if so.
+    /// - u64 - Page size, maximum number of pages, number of pages and number of stored pages
+    ///   followed by each stored page:
+    ///   - u64 - Index of the page.
+    ///   - [u8; page size] - Content of the page.
+    /// - u64 - Size of the program followed by the program in its binary format.
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut writer = SnapshotWriter::new();
+
+        writer.write_usize(self.program_counter);
+        writer.write_usize(self.stack_pointer);
+        writer.write_usize(self.stack_size);
+        writer.write_usize(self.frame_pointer);
+        writer.write_bool(self.overflow_flag);
+        writer.write_usize(self.max_call_depth);
+
+        writer.write_usize(self.call_stack.len());
+        for frame in &self.call_stack {
+            writer.write_usize(frame.return_address);
+            writer.write_usize(frame.stack_pointer);
+            writer.write_usize(frame.frame_pointer);
+        }
+
+        writer.write_bool(self.fuel.is_some());
+        if let Some(fuel) = self.fuel {
+            writer.write_u64(fuel);
+        }
+
+        let stored_pages: Vec<_> = self
+            .memory
+            .materialized_pages()
+            .filter(|(_, page)| page.iter().any(|v| *v != 0))
+            .collect();
+        writer.write_usize(self.memory.page_size());
+        writer.write_usize(self.memory.max_pages());
+        writer.write_usize(self.memory.pages());
+        writer.write_usize(stored_pages.len());
+        for (index, page) in stored_pages {
+            writer.write_usize(index);
+            writer.write_bytes(page);
+        }
+
+        let program = self.program.to_bytes();
+        writer.write_usize(program.len());
+        writer.write_bytes(&program);
+
+        writer.into_bytes()
+    }
+
+    /// Adds `amount` to the fuel budget, if any, to resume an execution that has run
+    /// out of fuel.
+    pub fn add_fuel(&mut self, amount: u64) {
+        if let Some(fuel) = &mut self.fuel {
+            *fuel = fuel.saturating_add(amount);
+        }
+    }
+
     pub fn pop_u8(&mut self) -> Result<u8, Action> {
         let value = self.peek_u8()?;
         self.stack_pointer -= std::mem::size_of::<u8>();
@@ -188,7 +461,7 @@ impl Processor {
     pub fn push_u8(&mut self, value: u8) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<u8>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_u8_at(self.stack_pointer, value)?;
@@ -200,7 +473,7 @@ impl Processor {
     pub fn push_u16(&mut self, value: u16) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<u16>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_u16_at(self.stack_pointer, value)?;
@@ -212,7 +485,7 @@ impl Processor {
     pub fn push_u32(&mut self, value: u32) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<u32>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_u32_at(self.stack_pointer, value)?;
@@ -224,7 +497,7 @@ impl Processor {
     pub fn push_u64(&mut self, value: u64) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<u64>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_u64_at(self.stack_pointer, value)?;
@@ -236,7 +509,7 @@ impl Processor {
     pub fn push_i8(&mut self, value: i8) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<i8>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_i8_at(self.stack_pointer, value)?;
@@ -248,7 +521,7 @@ impl Processor {
     pub fn push_i16(&mut self, value: i16) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<i16>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_i16_at(self.stack_pointer, value)?;
@@ -260,7 +533,7 @@ impl Processor {
     pub fn push_i32(&mut self, value: i32) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<i32>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_i32_at(self.stack_pointer, value)?;
@@ -272,7 +545,7 @@ impl Processor {
     pub fn push_i64(&mut self, value: i64) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<i64>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_i64_at(self.stack_pointer, value)?;
@@ -284,7 +557,7 @@ impl Processor {
     pub fn push_f32(&mut self, value: f32) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<f32>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_f32_at(self.stack_pointer, value)?;
@@ -296,7 +569,7 @@ impl Processor {
     pub fn push_f64(&mut self, value: f64) -> Result<(), Action> {
         let num_bytes = std::mem::size_of::<f64>();
         if self.stack_pointer + num_bytes > self.stack_size {
-            return Err(Action::Panic("Stack Overflow"));
+            return Err(Action::trap(TrapKind::StackOverflow));
         }
 
         self.memory.write_f64_at(self.stack_pointer, value)?;
@@ -308,7 +581,7 @@ impl Processor {
     pub fn peek_u8(&self) -> Result<u8, Action> {
         let num_bytes = std::mem::size_of::<u8>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -318,7 +591,7 @@ impl Processor {
     pub fn peek_u16(&self) -> Result<u16, Action> {
         let num_bytes = std::mem::size_of::<u16>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -328,7 +601,7 @@ impl Processor {
     pub fn peek_u32(&self) -> Result<u32, Action> {
         let num_bytes = std::mem::size_of::<u32>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -338,7 +611,7 @@ impl Processor {
     pub fn peek_u64(&self) -> Result<u64, Action> {
         let num_bytes = std::mem::size_of::<u64>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -348,7 +621,7 @@ impl Processor {
     pub fn peek_i8(&self) -> Result<i8, Action> {
         let num_bytes = std::mem::size_of::<i8>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -358,7 +631,7 @@ impl Processor {
     pub fn peek_i16(&self) -> Result<i16, Action> {
         let num_bytes = std::mem::size_of::<i16>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -368,7 +641,7 @@ impl Processor {
     pub fn peek_i32(&self) -> Result<i32, Action> {
         let num_bytes = std::mem::size_of::<i32>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -378,7 +651,7 @@ impl Processor {
     pub fn peek_i64(&self) -> Result<i64, Action> {
         let num_bytes = std::mem::size_of::<i64>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -388,7 +661,7 @@ impl Processor {
     pub fn peek_f32(&self) -> Result<f32, Action> {
         let num_bytes = std::mem::size_of::<f32>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -398,7 +671,7 @@ impl Processor {
     pub fn peek_f64(&self) -> Result<f64, Action> {
         let num_bytes = std::mem::size_of::<f64>();
         if num_bytes > self.stack_pointer {
-            return Err(Action::Panic("Stack underflow"));
+            return Err(Action::trap(TrapKind::StackUnderflow));
         }
 
         let start_index = self.stack_pointer - num_bytes;
@@ -464,4 +737,621 @@ impl Processor {
         self.program_counter += std::mem::size_of::<f64>();
         Ok(result)
     }
+
+    /// Jumps to the subroutine at `code_position` saving a call frame to return later
+    /// to the current program counter. The frame of the subroutine starts at the current
+    /// stack pointer.
+    pub fn call(&mut self, code_position: usize) -> Result<(), Action> {
+        if self.call_stack.len() >= self.max_call_depth {
+            return Err(Action::trap(TrapKind::CallStackOverflow));
+        }
+
+        let return_address = self.program_counter;
+        self.set_program_counter(code_position)?;
+        self.call_stack.push(CallFrame {
+            return_address,
+            stack_pointer: self.stack_pointer,
+            frame_pointer: self.frame_pointer,
+        });
+        self.frame_pointer = self.stack_pointer;
+
+        Ok(())
+    }
+
+    /// Removes the innermost call frame and jumps back to its return address.
+    /// The subroutine can leave values in the stack but cannot remove the ones that were
+    /// there when it was called.
+    pub fn return_from_call(&mut self) -> Result<(), Action> {
+        let frame = match self.call_stack.last() {
+            Some(v) => *v,
+            None => return Err(Action::trap(TrapKind::CallStackUnderflow)),
+        };
+
+        if self.stack_pointer < frame.stack_pointer {
+            return Err(Action::trap(TrapKind::StackUnderflow));
+        }
+
+        self.call_stack.pop();
+
+        self.program_counter = frame.return_address;
+        self.frame_pointer = frame.frame_pointer;
+
+        Ok(())
+    }
+
+    /// Calls the host function registered at `index`.
+    pub fn call_host(&mut self, index: u32) -> Result<(), Action> {
+        let function = match self.host_functions.get(index) {
+            Some(v) => v.clone(),
+            None => return Err(Action::trap(TrapKind::UnknownHostFunction(index))),
+        };
+
+        function(self)
+    }
+
+    /// Gets the code position placed `offset` bytes from the program counter. Positions before
+    /// the start of the program wrap around, so they are rejected as any other position
+    /// outside the code.
+    pub fn relative_code_position(&self, offset: i32) -> usize {
+        self.program_counter.wrapping_add(offset as isize as usize)
+    }
+
+    /// Gets the position of the stack value of `num_bytes` bytes placed at `offset` bytes from
+    /// the frame pointer, checking that it is inside the used part of the stack.
+    pub fn local_position(&self, offset: i32, num_bytes: usize) -> Result<usize, Action> {
+        let position = self.frame_pointer as i64 + offset as i64;
+        if position < 0 || position as usize + num_bytes > self.stack_pointer {
+            return Err(Action::trap(TrapKind::LocalOutOfBounds {
+                offset,
+                size: num_bytes,
+            }));
+        }
+
+        Ok(position as usize)
+    }
+
+    /// Executes the instruction pointed by the program counter.
+    /// Returns `Action::End` when the program counter has reached the end of the code.
+    /// Returns `Action::OutOfFuel` without executing the instruction when the fuel left
+    /// is lower than its cost, so the execution can be resumed after adding more fuel.
+    /// The traps raised by the instruction point to its position in the code.
+    pub fn step(&mut self) -> Result<(), Action> {
+        if self.program_counter >= self.program.code_pointer_end() {
+            return Err(Action::End);
+        }
+
+        if let Some(fuel) = self.fuel {
+            self.fuel = Some(self.consume_fuel(fuel)?);
+        }
+
+        // A locked tracer usually belongs to an outer step that is running a host function,
+        // so the nested steps are executed without notifying it.
+        match &self.tracer {
+            Some(tracer) => {
+                let tracer = tracer.clone();
+                let result = match tracer.try_lock() {
+                    Ok(mut tracer) => self.step_traced(&mut *tracer),
+                    Err(TryLockError::Poisoned(error)) => {
+                        self.step_traced(&mut *error.into_inner())
+                    }
+                    Err(TryLockError::WouldBlock) => self.execute_next(),
+                };
+                result
+            }
+            None => self.execute_next(),
+        }
+    }
+
+    /// Executes the instruction pointed by the program counter notifying `tracer`.
+    /// Unassigned opcodes are not notified because they do not decode to an instruction.
+    fn step_traced(&mut self, tracer: &mut dyn Tracer) -> Result<(), Action> {
+        let program_counter = self.program_counter;
+        let code = &self.program.program()[program_counter..self.program.code_pointer_end()];
+        let instruction = match Instruction::read_index(code).and_then(Instruction::from_index) {
+            Some(v) => v,
+            None => return self.execute_next(),
+        };
+
+        tracer.before_instruction(program_counter, instruction, self);
+        let result = self.execute_next();
+        tracer.after_instruction(program_counter, instruction, self, &result);
+
+        result
+    }
+
+    /// Subtracts the cost of the instruction pointed by the program counter from `fuel`.
+    fn consume_fuel(&self, fuel: u64) -> Result<u64, Action> {
+        let code = &self.program.program()[self.program_counter..self.program.code_pointer_end()];
+        let cost = match Instruction::read_index(code) {
+            Some(index) => self.fuel_costs.cost_of_index(index),
+            None => FUEL_DEFAULT_COST,
+        };
+
+        fuel.checked_sub(cost).ok_or(Action::OutOfFuel)
+    }
+
+    fn execute_next(&mut self) -> Result<(), Action> {
+        let program_counter = self.program_counter;
+        let opcode = self.code_next_u8()?;
+        INSTRUCTION_LIST[opcode as usize](self).map_err(|action| match action {
+            Action::Trap(trap) => Action::Trap(Trap {
+                program_counter,
+                ..trap
+            }),
+            action => action,
+        })
+    }
+
+    /// Executes instructions until the processor halts, traps, runs out of fuel or reaches
+    /// the end of the code.
+    /// A halted processor can be resumed by calling this method again.
+    pub fn run(&mut self) -> Action {
+        loop {
+            if let Err(action) = self.step() {
+                return action;
+            }
+        }
+    }
+
+    /// Executes at most `count` instructions, stopping before if the processor halts, traps,
+    /// runs out of fuel or reaches the end of the code.
+    pub fn run_for(&mut self, count: usize) -> Result<(), Action> {
+        for _ in 0..count {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::asm::assemble;
+    use crate::sasm::instructions::{Instruction, EXTENDED_OPCODE};
+    use crate::sasm::SNAPSHOT_MAGIC;
+
+    use super::*;
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Processor>();
+    }
+
+    #[test]
+    fn test_step() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const8 as u8,
+                0x12,
+                Instruction::Drop8 as u8,
+                Instruction::Unreachable as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.step().expect("[1] The step must succeed");
+        assert_eq!(
+            processor.program_counter(),
+            2,
+            "[1] The program counter is incorrect"
+        );
+        assert_eq!(
+            processor.peek_u8().unwrap(),
+            0x12,
+            "[1] The value stored in the stack is incorrect"
+        );
+
+        // Case 2
+        processor.step().expect("[2] The step must succeed");
+        assert_eq!(
+            processor.program_counter(),
+            3,
+            "[2] The program counter is incorrect"
+        );
+        assert_eq!(
+            processor.stack_pointer(),
+            0,
+            "[2] The stack pointer is incorrect"
+        );
+
+        // Case 3
+        let result = processor.step().expect_err("[3] The step must fail");
+        assert_eq!(
+            result.unwrap_trap(),
+            Trap {
+                program_counter: 3,
+                kind: TrapKind::Unreachable
+            },
+            "[3] The trap is incorrect"
+        );
+
+        // Case 4
+        let result = processor.step().expect_err("[4] The step must fail");
+        assert!(result.is_end(), "[4] The end of the code must be reached");
+    }
+
+    #[test]
+    fn test_invalid_opcode() {
+        let program = Program::new_for_tests(
+            vec![Instruction::Nop as u8, 0xfe, EXTENDED_OPCODE, 0xf0],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        let result = processor.run();
+        assert_eq!(
+            result.unwrap_trap(),
+            Trap {
+                program_counter: 1,
+                kind: TrapKind::InvalidOpcode(0xfe)
+            },
+            "[1] The trap is incorrect"
+        );
+
+        // Case 2
+        processor.set_program_counter(2).unwrap();
+        let result = processor.run();
+        assert_eq!(
+            result.unwrap_trap(),
+            Trap {
+                program_counter: 2,
+                kind: TrapKind::InvalidOpcode(0x1f0)
+            },
+            "[2] The trap is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let program = Program::new_for_tests(
+            vec![
+                0xff,
+                Instruction::Const8 as u8,
+                0x12,
+                Instruction::Debug as u8,
+                Instruction::Const8 as u8,
+                0x34,
+            ],
+            0,
+            1,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+        assert_eq!(
+            processor.program_counter(),
+            1,
+            "[0] The program counter must start at the code"
+        );
+
+        // Case 1
+        let result = processor.run();
+        assert!(result.is_halt(), "[1] The processor must halt");
+        assert_eq!(
+            processor.program_counter(),
+            4,
+            "[1] The program counter is incorrect"
+        );
+
+        // Case 2
+        let result = processor.run();
+        assert!(result.is_end(), "[2] The end of the code must be reached");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x3412,
+            "[2] The values stored in the stack are incorrect"
+        );
+    }
+
+    #[test]
+    fn test_run_for() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Nop as u8,
+                Instruction::Nop as u8,
+                Instruction::Nop as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.run_for(2).expect("[1] The run must succeed");
+        assert_eq!(
+            processor.program_counter(),
+            2,
+            "[1] The program counter is incorrect"
+        );
+
+        // Case 2
+        let result = processor
+            .run_for(2)
+            .expect_err("[2] The run must reach the end");
+        assert!(result.is_end(), "[2] The end of the code must be reached");
+        assert_eq!(
+            processor.program_counter(),
+            3,
+            "[2] The program counter is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_fuel() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const32 as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Branch as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+        processor.set_fuel(Some(5));
+
+        // Case 1
+        let result = processor.run();
+        assert!(result.is_out_of_fuel(), "[1] The fuel must run out");
+        assert_eq!(
+            processor.program_counter(),
+            5,
+            "[1] The program counter is incorrect"
+        );
+        assert_eq!(processor.fuel(), Some(0), "[1] The fuel is incorrect");
+
+        // Case 2
+        processor.fuel_costs_mut().set_cost(Instruction::Branch, 3);
+        processor.add_fuel(2);
+        let result = processor.step().expect_err("[2] The step must fail");
+        assert!(result.is_out_of_fuel(), "[2] The fuel must run out");
+        assert_eq!(
+            processor.program_counter(),
+            5,
+            "[2] The program counter is incorrect"
+        );
+
+        // Case 3
+        processor.add_fuel(1);
+        processor.step().expect("[3] The step must succeed");
+        assert_eq!(
+            processor.program_counter(),
+            0,
+            "[3] The program counter is incorrect"
+        );
+        assert_eq!(processor.fuel(), Some(0), "[3] The fuel is incorrect");
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let program = assemble(
+            "call_rel sub\n\
+             debug\n\
+             sub: const_32 0x12345678\n\
+             const_8 0x9a\n\
+             drop_8\n\
+             return\n",
+        )
+        .unwrap();
+        let mut processor = Processor::new_empty(program, 20);
+        processor.set_fuel(Some(100));
+        processor.step().expect("The call must succeed");
+        processor.step().expect("The const_32 must succeed");
+        processor.step().expect("The const_8 must succeed");
+
+        // Case 1
+        let snapshot = processor.to_snapshot();
+        let mut restored =
+            Processor::from_snapshot(&snapshot).expect("[1] The restore must succeed");
+        assert_eq!(
+            restored.program_counter(),
+            processor.program_counter(),
+            "[1] The program counter is incorrect"
+        );
+        assert_eq!(
+            restored.stack_pointer(),
+            processor.stack_pointer(),
+            "[1] The stack pointer is incorrect"
+        );
+        assert_eq!(restored.stack_size(), 20, "[1] The stack size is incorrect");
+        assert_eq!(
+            restored.call_stack(),
+            processor.call_stack(),
+            "[1] The call stack is incorrect"
+        );
+        assert_eq!(restored.fuel(), Some(97), "[1] The fuel is incorrect");
+        let mut bytes = [0; 20];
+        let mut restored_bytes = [0; 20];
+        processor.memory().inspect_at(0, &mut bytes).unwrap();
+        restored
+            .memory()
+            .inspect_at(0, &mut restored_bytes)
+            .unwrap();
+        assert_eq!(restored_bytes, bytes, "[1] The memory is incorrect");
+
+        // Case 2
+        let result = restored.run();
+        assert!(result.is_halt(), "[2] The processor must halt");
+        assert!(processor.run().is_halt(), "[2] The processor must halt");
+        assert_eq!(
+            restored.program_counter(),
+            processor.program_counter(),
+            "[2] The program counter is incorrect"
+        );
+        assert_eq!(
+            restored.stack_pointer(),
+            processor.stack_pointer(),
+            "[2] The stack pointer is incorrect"
+        );
+
+        // Case 3
+        assert_eq!(
+            Processor::from_snapshot(&snapshot[..snapshot.len() - 1]).err(),
+            Some(SnapshotError::Truncated),
+            "[3] The snapshot must be truncated"
+        );
+
+        // Case 4
+        let mut snapshot = snapshot;
+        snapshot[0] = b'X';
+        assert_eq!(
+            Processor::from_snapshot(&snapshot).err(),
+            Some(SnapshotError::InvalidMagic),
+            "[4] The magic bytes must be invalid"
+        );
+
+        // Case 5: a call frame that returns outside the code.
+        snapshot[0] = SNAPSHOT_MAGIC[0];
+        let return_address_position = SNAPSHOT_MAGIC.len() + 2 + 6 * 8 + 1;
+        snapshot[return_address_position..return_address_position + 8]
+            .copy_from_slice(&0xffffu64.to_le_bytes());
+        assert_eq!(
+            Processor::from_snapshot(&snapshot).err(),
+            Some(SnapshotError::InvalidState),
+            "[5] The call frame must be invalid"
+        );
+    }
+
+    #[test]
+    fn test_call_return() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const32 as u8,
+                0x0a,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Call as u8,
+                Instruction::Const8 as u8,
+                0x22,
+                Instruction::Debug as u8,
+                Instruction::Unreachable as u8,
+                Instruction::Const8 as u8,
+                0x11,
+                Instruction::Return as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+
+        // Case 1
+        processor.run_for(3).expect("[1] The run must succeed");
+        assert_eq!(
+            processor.call_stack(),
+            &[CallFrame {
+                return_address: 6,
+                stack_pointer: 0,
+                frame_pointer: 0,
+            }],
+            "[1] The call stack is incorrect"
+        );
+
+        // Case 2
+        let result = processor.run();
+        assert!(result.is_halt(), "[2] The processor must halt");
+        assert_eq!(processor.call_depth(), 0, "[2] The call depth is incorrect");
+        assert_eq!(
+            processor.pop_u16().unwrap(),
+            0x2211,
+            "[2] The values stored in the stack are incorrect"
+        );
+
+        // Case 3
+        let result = processor
+            .return_from_call()
+            .expect_err("[3] The return must fail");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::CallStackUnderflow,
+            "[3] The error is incorrect"
+        );
+
+        // Case 4
+        processor.set_max_call_depth(0);
+        let result = processor.call(0).expect_err("[4] The call must fail");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::CallStackOverflow,
+            "[4] The error is incorrect"
+        );
+
+        // Case 5
+        processor.set_max_call_depth(1);
+        processor.push_u8(0x33).unwrap();
+        processor.call(0x0a).expect("[5] The call must succeed");
+        processor.pop_u8().unwrap();
+        let result = processor
+            .return_from_call()
+            .expect_err("[5] The return must fail");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::StackUnderflow,
+            "[5] The error is incorrect"
+        );
+        assert_eq!(processor.call_depth(), 1, "[5] The call depth is incorrect");
+    }
+
+    #[test]
+    fn test_call_host() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const32 as u8,
+                0x02,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Const32 as u8,
+                0x03,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::CallHost as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::CallHost as u8,
+                0x01,
+                0x00,
+                0x00,
+                0x00,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+        processor.host_functions_mut().register("add", |processor| {
+            let right = processor.pop_u32()?;
+            let left = processor.pop_u32()?;
+            processor.push_u32(left + right)
+        });
+
+        // Case 1
+        processor.run_for(3).expect("[1] The run must succeed");
+        assert_eq!(
+            processor.pop_u32().unwrap(),
+            5,
+            "[1] The result of the host function is incorrect"
+        );
+
+        // Case 2
+        let result = processor.run();
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::UnknownHostFunction(1),
+            "[2] The error is incorrect"
+        );
+    }
 }
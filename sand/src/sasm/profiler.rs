@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::sasm::instructions::Instruction;
+use crate::sasm::{Action, Processor, Tracer};
+
+/// The execution statistics of a host function.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct HostFunctionProfile {
+    /// The name the function was registered with, if any.
+    pub name: Option<String>,
+
+    /// The number of times the function has been called.
+    pub calls: u64,
+
+    /// The total time spent in the function.
+    pub time: Duration,
+}
+
+/// A tracer that profiles the execution of a processor: it counts the executions of each
+/// instruction, the hits of each code position and the calls and time spent in each host
+/// function. It also records the number of instructions executed in each stack of
+/// subroutines to export them as folded stacks.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    instruction_counts: HashMap<Instruction, u64>,
+    program_counter_hits: HashMap<usize, u64>,
+    host_functions: HashMap<u32, HostFunctionProfile>,
+    stacks: HashMap<Vec<usize>, u64>,
+    /// The code positions of the subroutines being executed, the innermost at the end.
+    stack: Vec<usize>,
+    host_call_start: Option<Instant>,
+}
+
+impl Profiler {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn instruction_counts(&self) -> &HashMap<Instruction, u64> {
+        &self.instruction_counts
+    }
+
+    #[inline]
+    pub fn program_counter_hits(&self) -> &HashMap<usize, u64> {
+        &self.program_counter_hits
+    }
+
+    #[inline]
+    pub fn host_functions(&self) -> &HashMap<u32, HostFunctionProfile> {
+        &self.host_functions
+    }
+
+    /// The total number of executed instructions.
+    pub fn total_instructions(&self) -> u64 {
+        self.instruction_counts.values().sum()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Writes the statistics as tables sorted by count: the executions per instruction,
+    /// the hottest code positions and the host functions.
+    pub fn report(&self, max_hot_spots: usize) -> String {
+        let mut result = String::new();
+        let total = self.total_instructions();
+
+        let mut instructions: Vec<_> = self.instruction_counts.iter().collect();
+        instructions.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then(a.name().cmp(b.name()))
+        });
+        writeln!(result, "{:<24} {:>12} {:>8}", "instruction", "count", "%").unwrap();
+        for (instruction, count) in instructions {
+            writeln!(
+                result,
+                "{:<24} {:>12} {:>8.2}",
+                instruction.name(),
+                count,
+                *count as f64 * 100.0 / total as f64
+            )
+            .unwrap();
+        }
+        writeln!(result, "{:<24} {:>12}", "total", total).unwrap();
+
+        let mut hot_spots: Vec<_> = self.program_counter_hits.iter().collect();
+        hot_spots.sort_by(|(a, a_hits), (b, b_hits)| b_hits.cmp(a_hits).then(a.cmp(b)));
+        writeln!(result).unwrap();
+        writeln!(result, "{:<24} {:>12}", "position", "hits").unwrap();
+        for (position, hits) in hot_spots.into_iter().take(max_hot_spots) {
+            writeln!(result, "{:<#24x} {:>12}", position, hits).unwrap();
+        }
+
+        if !self.host_functions.is_empty() {
+            let mut host_functions: Vec<_> = self.host_functions.iter().collect();
+            host_functions.sort_by(|(a, a_profile), (b, b_profile)| {
+                b_profile.time.cmp(&a_profile.time).then(a.cmp(b))
+            });
+            writeln!(result).unwrap();
+            writeln!(
+                result,
+                "{:<24} {:>12} {:>16}",
+                "host function", "calls", "time (us)"
+            )
+            .unwrap();
+            for (index, profile) in host_functions {
+                let name = match &profile.name {
+                    Some(v) => v.clone(),
+                    None => format!("#{}", index),
+                };
+                writeln!(
+                    result,
+                    "{:<24} {:>12} {:>16}",
+                    name,
+                    profile.calls,
+                    profile.time.as_micros()
+                )
+                .unwrap();
+            }
+        }
+
+        result
+    }
+
+    /// Writes the number of instructions executed in each stack of subroutines in the folded
+    /// stacks format of flamegraph tools: a line per stack with its frames separated by
+    /// semicolons followed by the count. The root frame is `main` and the others are
+    /// the code positions of the subroutines.
+    pub fn folded_stacks(&self) -> String {
+        let mut stacks: Vec<_> = self
+            .stacks
+            .iter()
+            .map(|(stack, count)| {
+                let mut line = String::from("main");
+                for position in stack {
+                    write!(line, ";{:#010x}", position).unwrap();
+                }
+                (line, count)
+            })
+            .collect();
+        stacks.sort();
+
+        let mut result = String::new();
+        for (line, count) in stacks {
+            writeln!(result, "{} {}", line, count).unwrap();
+        }
+
+        result
+    }
+}
+
+impl Tracer for Profiler {
+    fn before_instruction(
+        &mut self,
+        program_counter: usize,
+        instruction: Instruction,
+        processor: &Processor,
+    ) {
+        *self.instruction_counts.entry(instruction).or_insert(0) += 1;
+        *self
+            .program_counter_hits
+            .entry(program_counter)
+            .or_insert(0) += 1;
+
+        match self.stacks.get_mut(self.stack.as_slice()) {
+            Some(count) => *count += 1,
+            None => {
+                self.stacks.insert(self.stack.clone(), 1);
+            }
+        }
+
+        if instruction == Instruction::CallHost {
+            if let Ok(index) = processor.program().read_u32_at(program_counter + 1) {
+                let profile = self.host_functions.entry(index).or_default();
+                if profile.name.is_none() {
+                    profile.name = processor.host_functions().name(index).map(String::from);
+                }
+            }
+
+            self.host_call_start = Some(Instant::now());
+        }
+    }
+
+    fn after_instruction(
+        &mut self,
+        program_counter: usize,
+        instruction: Instruction,
+        processor: &Processor,
+        result: &Result<(), Action>,
+    ) {
+        match instruction {
+            Instruction::CallHost => {
+                let time = match self.host_call_start.take() {
+                    Some(v) => v.elapsed(),
+                    None => return,
+                };
+
+                if let Ok(index) = processor.program().read_u32_at(program_counter + 1) {
+                    let profile = self.host_functions.entry(index).or_default();
+                    profile.calls += 1;
+                    profile.time += time;
+                }
+            }
+            Instruction::Call | Instruction::CallRel if result.is_ok() => {
+                self.stack.push(processor.program_counter());
+            }
+            Instruction::Return if result.is_ok() => {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use crate::sasm::asm::assemble;
+
+    use super::*;
+
+    #[test]
+    fn test_profiler() {
+        let program = assemble(
+            "call_rel sub\n\
+             call_rel sub\n\
+             call_host 0\n\
+             debug\n\
+             sub: nop\n\
+             return\n",
+        )
+        .unwrap();
+        let mut processor = Processor::new_empty(program, 20);
+        processor.host_functions_mut().register("print", |_| Ok(()));
+        let profiler = Arc::new(Mutex::new(Profiler::new()));
+        processor.set_tracer(Some(profiler.clone()));
+
+        let result = processor.run();
+        assert!(result.is_halt(), "The processor must halt");
+
+        let profiler = profiler.lock().unwrap();
+        assert_eq!(
+            profiler.total_instructions(),
+            8,
+            "The total of instructions is incorrect"
+        );
+        assert_eq!(
+            profiler.instruction_counts()[&Instruction::CallRel],
+            2,
+            "The count of call_rel is incorrect"
+        );
+        assert_eq!(
+            profiler.program_counter_hits()[&0x12],
+            2,
+            "The hits of the subroutine are incorrect"
+        );
+
+        let host_function = &profiler.host_functions()[&0];
+        assert_eq!(
+            host_function.name.as_deref(),
+            Some("print"),
+            "The name of the host function is incorrect"
+        );
+        assert_eq!(
+            host_function.calls, 1,
+            "The calls of the host function are incorrect"
+        );
+
+        assert_eq!(
+            profiler.folded_stacks(),
+            "main 4\nmain;0x00000012 4\n",
+            "The folded stacks are incorrect"
+        );
+
+        let report = profiler.report(2);
+        assert!(
+            report.starts_with(
+                "instruction                     count        %\n\
+                 call_rel                            2    25.00\n"
+            ),
+            "The report is incorrect:\n{}",
+            report
+        );
+    }
+}
@@ -1,5 +1,34 @@
-use crate::sasm::Action;
-
+use std::convert::TryInto;
+
+use crate::sasm::{Action, TrapKind};
+
+/// The magic bytes every program file starts with.
+pub const PROGRAM_MAGIC: [u8; 4] = *b"SASM";
+
+/// The version of the program format that `Program::to_bytes` writes.
+pub const PROGRAM_FORMAT_VERSION: u16 = 1;
+
+/// The size of the fixed part of the header: magic, version and number of sections.
+const PROGRAM_HEADER_SIZE: usize = 8;
+
+/// The size of an entry of the section table: kind, offset and size.
+const PROGRAM_SECTION_ENTRY_SIZE: usize = 9;
+
+/// A compiled program split in a data and a code section.
+///
+/// The binary format of a program file is (all numbers in little-endian):
+/// - [u8; 4] - Magic bytes: `PROGRAM_MAGIC`.
+/// - u16 - Format version: `PROGRAM_FORMAT_VERSION`.
+/// - u16 - Number of sections.
+/// - Section table, one entry per section:
+///   - u8  - Section kind, see `ProgramSection`.
+///   - u32 - Offset of the section from the start of the file.
+///   - u32 - Size of the section in bytes.
+/// - Contents of the sections.
+///
+/// Missing sections are considered empty and sections of unknown kinds are skipped, so that
+/// files with sections added by newer versions can still be read.
+#[derive(Debug, Clone)]
 pub struct Program {
     program: Vec<u8>,
     data_pointer: usize,
@@ -9,8 +38,8 @@ pub struct Program {
 impl Program {
     // CONSTRUCTORS -----------------------------------------------------------
 
+    /// Builds a program whose content is only code.
     pub fn new(program: Vec<u8>) -> Program {
-        // TODO improve reading the format and the different sections.
         Program {
             program,
             data_pointer: 0,
@@ -18,6 +47,73 @@ impl Program {
         }
     }
 
+    /// Builds a program from its data and code sections.
+    pub fn from_sections(data: &[u8], code: &[u8]) -> Program {
+        let mut program = Vec::with_capacity(data.len() + code.len());
+        program.extend_from_slice(data);
+        program.extend_from_slice(code);
+
+        Program {
+            program,
+            data_pointer: 0,
+            code_pointer: data.len(),
+        }
+    }
+
+    /// Reads a program from its binary format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Program, ProgramError> {
+        if bytes.len() < PROGRAM_MAGIC.len() || bytes[..PROGRAM_MAGIC.len()] != PROGRAM_MAGIC {
+            return Err(ProgramError::InvalidMagic);
+        }
+
+        if bytes.len() < PROGRAM_HEADER_SIZE {
+            return Err(ProgramError::TruncatedHeader);
+        }
+
+        let version = u16::from_le_bytes(bytes[4..6].try_into().unwrap());
+        if version != PROGRAM_FORMAT_VERSION {
+            return Err(ProgramError::UnsupportedVersion(version));
+        }
+
+        let section_count = u16::from_le_bytes(bytes[6..8].try_into().unwrap()) as usize;
+        let table_end = PROGRAM_HEADER_SIZE + section_count * PROGRAM_SECTION_ENTRY_SIZE;
+        if bytes.len() < table_end {
+            return Err(ProgramError::TruncatedHeader);
+        }
+
+        let mut data: Option<&[u8]> = None;
+        let mut code: Option<&[u8]> = None;
+        for entry in bytes[PROGRAM_HEADER_SIZE..table_end].chunks(PROGRAM_SECTION_ENTRY_SIZE) {
+            let offset = u32::from_le_bytes(entry[1..5].try_into().unwrap()) as usize;
+            let size = u32::from_le_bytes(entry[5..9].try_into().unwrap()) as usize;
+
+            let content = match offset.checked_add(size) {
+                Some(end) if end <= bytes.len() => &bytes[offset..end],
+                _ => return Err(ProgramError::SectionOutOfBounds(entry[0])),
+            };
+
+            let section = match ProgramSection::from_id(entry[0]) {
+                Some(v) => v,
+                None => continue,
+            };
+            let slot = match section {
+                ProgramSection::Data => &mut data,
+                ProgramSection::Code => &mut code,
+            };
+
+            if slot.is_some() {
+                return Err(ProgramError::DuplicatedSection(section));
+            }
+
+            *slot = Some(content);
+        }
+
+        Ok(Program::from_sections(
+            data.unwrap_or_default(),
+            code.unwrap_or_default(),
+        ))
+    }
+
     #[cfg(test)]
     pub fn new_for_tests(program: Vec<u8>, data_pointer: usize, code_pointer: usize) -> Program {
         Program {
@@ -59,13 +155,52 @@ impl Program {
         self.size()
     }
 
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.program[self.data_pointer..self.code_pointer]
+    }
+
+    #[inline]
+    pub fn code(&self) -> &[u8] {
+        &self.program[self.code_pointer..]
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// Writes the program in its binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let data = self.data();
+        let code = self.code();
+        let sections = [(ProgramSection::Data, data), (ProgramSection::Code, code)];
+
+        let mut offset = PROGRAM_HEADER_SIZE + sections.len() * PROGRAM_SECTION_ENTRY_SIZE;
+        let mut bytes = Vec::with_capacity(offset + data.len() + code.len());
+        bytes.extend_from_slice(&PROGRAM_MAGIC);
+        bytes.extend_from_slice(&PROGRAM_FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(sections.len() as u16).to_le_bytes());
+
+        for (section, content) in &sections {
+            bytes.push(*section as u8);
+            bytes.extend_from_slice(&(offset as u32).to_le_bytes());
+            bytes.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            offset += content.len();
+        }
+
+        for (_, content) in &sections {
+            bytes.extend_from_slice(content);
+        }
+
+        bytes
+    }
+
     pub fn read_at(&self, index: usize, bytes: &mut [u8]) -> Result<(), Action> {
         let num_bytes = bytes.len();
         let last_index = index + num_bytes;
         if last_index > self.size() {
-            return Err(Action::Panic("Segmentation Fault"));
+            return Err(Action::trap(TrapKind::ProgramOutOfBounds {
+                address: index,
+                size: num_bytes,
+            }));
         }
 
         bytes[..].clone_from_slice(&self.program[index..last_index]);
@@ -144,14 +279,50 @@ impl Program {
     }
 }
 
+/// The sections of a program file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ProgramSection {
+    Data = 0,
+    Code = 1,
+}
+
+impl ProgramSection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn from_id(id: u8) -> Option<ProgramSection> {
+        match id {
+            0 => Some(ProgramSection::Data),
+            1 => Some(ProgramSection::Code),
+            _ => None,
+        }
+    }
+}
+
+/// The errors that can occur while reading a program file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ProgramError {
+    /// The file does not start with `PROGRAM_MAGIC`.
+    InvalidMagic,
+
+    /// The format version is not supported.
+    UnsupportedVersion(u16),
+
+    /// The file ends before the header or the section table are complete.
+    TruncatedHeader,
+
+    /// The section table contains the same section more than once.
+    DuplicatedSection(ProgramSection),
+
+    /// The section of the specified kind, which can be unknown, exceeds the end of the file.
+    SectionOutOfBounds(u8),
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
 #[cfg(test)]
 mod test {
-    use std::convert::TryInto;
-
     use super::*;
 
     #[test]
@@ -176,7 +347,13 @@ mod test {
         let result = program
             .read_at(5, &mut bytes)
             .expect_err("[1] The read must fail");
-        assert_eq!(result.unwrap_panic(), "Segmentation Fault");
+        assert_eq!(
+            result.unwrap_trap().kind,
+            TrapKind::ProgramOutOfBounds {
+                address: 5,
+                size: 1
+            }
+        );
 
         // Case 2: read many bytes.
         let mut bytes = [0; 3];
@@ -243,4 +420,135 @@ mod test {
         let result = program.read_f64_at(0).expect("[10] The read must succeed");
         assert_eq!(result, value, "[10] The value is incorrect");
     }
+
+    #[test]
+    fn test_bytes() {
+        let program = Program::from_sections(&[0x01, 0x02], &[0x03, 0x04, 0x05]);
+        let bytes = program.to_bytes();
+
+        assert_eq!(&bytes[..4], b"SASM", "[1] The magic is incorrect");
+
+        let result = Program::from_bytes(&bytes).expect("[2] The read must succeed");
+        assert_eq!(result.data(), &[0x01, 0x02], "[2] The data is incorrect");
+        assert_eq!(
+            result.code(),
+            &[0x03, 0x04, 0x05],
+            "[2] The code is incorrect"
+        );
+        assert_eq!(
+            result.data_pointer(),
+            0,
+            "[2] The data pointer is incorrect"
+        );
+        assert_eq!(
+            result.code_pointer(),
+            2,
+            "[2] The code pointer is incorrect"
+        );
+        assert_eq!(result.to_bytes(), bytes, "[2] The bytes are incorrect");
+    }
+
+    #[test]
+    fn test_bytes_missing_sections() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&PROGRAM_MAGIC);
+        bytes.extend_from_slice(&PROGRAM_FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(ProgramSection::Code as u8);
+        bytes.extend_from_slice(&17u32.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.push(0x2a);
+
+        let result = Program::from_bytes(&bytes).expect("The read must succeed");
+        assert_eq!(result.data(), &[], "The data is incorrect");
+        assert_eq!(result.code(), &[0x2a], "The code is incorrect");
+    }
+
+    #[test]
+    fn test_bytes_errors() {
+        let bytes = Program::from_sections(&[0x01], &[0x02]).to_bytes();
+
+        // Case 1: invalid magic.
+        let mut case = bytes.clone();
+        case[0] = b'X';
+        let result = Program::from_bytes(&case).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::InvalidMagic),
+            "[1] Incorrect error"
+        );
+
+        let result = Program::from_bytes(b"SA").err();
+        assert_eq!(
+            result,
+            Some(ProgramError::InvalidMagic),
+            "[1] Incorrect error"
+        );
+
+        // Case 2: unsupported version.
+        let mut case = bytes.clone();
+        case[4] = 0x09;
+        let result = Program::from_bytes(&case).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::UnsupportedVersion(9)),
+            "[2] Incorrect error"
+        );
+
+        // Case 3: truncated header.
+        let result = Program::from_bytes(&bytes[..6]).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::TruncatedHeader),
+            "[3] Incorrect error"
+        );
+
+        let result = Program::from_bytes(&bytes[..20]).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::TruncatedHeader),
+            "[3] Incorrect error"
+        );
+
+        // Case 4: unknown section.
+        let mut case = bytes.clone();
+        case[8] = 0x7f;
+        let program = Program::from_bytes(&case).expect("[4] The unknown section must be skipped");
+        assert_eq!(
+            program.program(),
+            &vec![0x02],
+            "[4] The program is incorrect"
+        );
+        assert_eq!(
+            program.code_pointer(),
+            0,
+            "[4] The code pointer is incorrect"
+        );
+
+        case[13] = 0xff;
+        let result = Program::from_bytes(&case).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::SectionOutOfBounds(0x7f)),
+            "[4] Incorrect error"
+        );
+
+        // Case 5: duplicated section.
+        let mut case = bytes.clone();
+        case[17] = ProgramSection::Data as u8;
+        let result = Program::from_bytes(&case).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::DuplicatedSection(ProgramSection::Data)),
+            "[5] Incorrect error"
+        );
+
+        // Case 6: section out of bounds.
+        let result = Program::from_bytes(&bytes[..bytes.len() - 1]).err();
+        assert_eq!(
+            result,
+            Some(ProgramError::SectionOutOfBounds(ProgramSection::Code as u8)),
+            "[6] Incorrect error"
+        );
+    }
 }
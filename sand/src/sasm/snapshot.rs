@@ -0,0 +1,137 @@
+use std::convert::TryInto;
+
+use crate::sasm::ProgramError;
+
+/// The magic bytes every snapshot starts with.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"SSNP";
+
+/// The version of the snapshot format that `Processor::to_snapshot` writes.
+pub const SNAPSHOT_FORMAT_VERSION: u16 = 1;
+
+/// The errors that can occur while restoring a snapshot.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SnapshotError {
+    /// The snapshot does not start with `SNAPSHOT_MAGIC`.
+    InvalidMagic,
+
+    /// The format version is not supported.
+    UnsupportedVersion(u16),
+
+    /// The snapshot ends before all the state is read.
+    Truncated,
+
+    /// The snapshot contains bytes after the state.
+    TrailingBytes,
+
+    /// The program stored in the snapshot is invalid.
+    InvalidProgram(ProgramError),
+
+    /// The stored state is inconsistent, e.g. the stack does not fit in the memory or a page
+    /// is placed after the last one.
+    InvalidState,
+}
+
+/// Writes the values of a snapshot in little-endian.
+#[derive(Debug, Default)]
+pub(crate) struct SnapshotWriter {
+    bytes: Vec<u8>,
+}
+
+impl SnapshotWriter {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new() -> SnapshotWriter {
+        let mut writer = SnapshotWriter::default();
+        writer.write_bytes(&SNAPSHOT_MAGIC);
+        writer.write_bytes(&SNAPSHOT_FORMAT_VERSION.to_le_bytes());
+        writer
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.bytes.push(value as u8);
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        self.write_bytes(&(value as u64).to_le_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Reads the values of a snapshot written by `SnapshotWriter`.
+pub(crate) struct SnapshotReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SnapshotReader<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Checks the header of the snapshot and creates a reader for its content.
+    pub fn new(bytes: &'a [u8]) -> Result<SnapshotReader<'a>, SnapshotError> {
+        if bytes.len() < SNAPSHOT_MAGIC.len() || bytes[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+
+        let mut reader = SnapshotReader {
+            bytes: &bytes[SNAPSHOT_MAGIC.len()..],
+        };
+        let version = u16::from_le_bytes(reader.read_bytes(2)?.try_into().unwrap());
+        if version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        Ok(reader)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn read_bytes(&mut self, size: usize) -> Result<&'a [u8], SnapshotError> {
+        if size > self.bytes.len() {
+            return Err(SnapshotError::Truncated);
+        }
+
+        let (result, rest) = self.bytes.split_at(size);
+        self.bytes = rest;
+        Ok(result)
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, SnapshotError> {
+        match self.read_bytes(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SnapshotError::InvalidState),
+        }
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, SnapshotError> {
+        let bytes = self.read_bytes(std::mem::size_of::<u64>())?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn read_usize(&mut self) -> Result<usize, SnapshotError> {
+        self.read_u64()?
+            .try_into()
+            .map_err(|_| SnapshotError::InvalidState)
+    }
+
+    /// Checks that the whole snapshot has been read.
+    pub fn finish(self) -> Result<(), SnapshotError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(SnapshotError::TrailingBytes)
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use crate::sasm::disasm::disassemble_instruction;
+use crate::sasm::instructions::Instruction;
+use crate::sasm::{Action, Processor};
+
+/// The number of bytes of the top of the stack written by `LineTracer`.
+const LINE_TRACER_STACK_TOP_SIZE: usize = 8;
+
+/// A tracer shared between a processor and its owner, which can inspect it after
+/// the execution. It is `Send` so that processors can be moved to other threads.
+pub type SharedTracer = Arc<Mutex<dyn Tracer + Send>>;
+
+/// Observes the execution of a processor instruction by instruction.
+/// Both methods do nothing by default. The steps executed while the tracer is locked,
+/// i.e. by host functions during the notified instruction or while another thread holds it,
+/// are not notified.
+pub trait Tracer {
+    /// Called before executing `instruction`, placed at `program_counter`.
+    fn before_instruction(
+        &mut self,
+        _program_counter: usize,
+        _instruction: Instruction,
+        _processor: &Processor,
+    ) {
+    }
+
+    /// Called after executing `instruction`, placed at `program_counter`, with its `result`.
+    fn after_instruction(
+        &mut self,
+        _program_counter: usize,
+        _instruction: Instruction,
+        _processor: &Processor,
+        _result: &Result<(), Action>,
+    ) {
+    }
+}
+
+/// A tracer that writes a line per executed instruction with its position, its disassembly,
+/// the stack pointer and the top bytes of the stack after executing it.
+/// Write errors are ignored so that they do not alter the execution.
+pub struct LineTracer<W: Write> {
+    output: W,
+}
+
+impl<W: Write> LineTracer<W> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(output: W) -> LineTracer<W> {
+        LineTracer { output }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    #[inline]
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_output(self) -> W {
+        self.output
+    }
+}
+
+impl<W: Write> Tracer for LineTracer<W> {
+    fn after_instruction(
+        &mut self,
+        program_counter: usize,
+        instruction: Instruction,
+        processor: &Processor,
+        result: &Result<(), Action>,
+    ) {
+        let text = match disassemble_instruction(processor.program(), program_counter) {
+            Some((text, _)) => text,
+            None => instruction.name().to_string(),
+        };
+        let stack_pointer = processor.stack_pointer();
+        let _ = write!(
+            self.output,
+            "{:#010x}  {}  | sp: {:#010x}",
+            program_counter, text, stack_pointer
+        );
+
+        let top_size = stack_pointer.min(LINE_TRACER_STACK_TOP_SIZE);
+        let mut top = [0; LINE_TRACER_STACK_TOP_SIZE];
+        let top = &mut top[..top_size];
+        if top_size != 0
+            && processor
+                .memory()
+                .inspect_at(stack_pointer - top_size, top)
+                .is_ok()
+        {
+            let _ = write!(self.output, " | top:");
+            for byte in top.iter() {
+                let _ = write!(self.output, " {:02x}", byte);
+            }
+        }
+
+        if let Err(Action::Trap(trap)) = result {
+            let _ = write!(self.output, " | trap: {:?}", trap.kind);
+        }
+
+        let _ = writeln!(self.output);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::asm::assemble;
+    use crate::sasm::Program;
+
+    use super::*;
+
+    #[test]
+    fn test_line_tracer() {
+        let program = Program::new_for_tests(
+            vec![
+                Instruction::Const8 as u8,
+                0x12,
+                Instruction::Const16 as u8,
+                0x34,
+                0x56,
+                Instruction::Drop16 as u8,
+                Instruction::Unreachable as u8,
+            ],
+            0,
+            0,
+        );
+        let mut processor = Processor::new_empty(program, 20);
+        let tracer = Arc::new(Mutex::new(LineTracer::new(Vec::new())));
+        processor.set_tracer(Some(tracer.clone()));
+
+        let result = processor.run();
+        assert!(result.is_trap(), "The processor must trap");
+
+        let output = String::from_utf8(tracer.lock().unwrap().output().clone()).unwrap();
+        assert_eq!(
+            output,
+            "0x00000000  const_8 0x12  | sp: 0x00000001 | top: 12\n\
+             0x00000002  const_16 0x5634  | sp: 0x00000003 | top: 12 34 56\n\
+             0x00000005  drop_16  | sp: 0x00000001 | top: 12\n\
+             0x00000006  unreachable  | sp: 0x00000001 | top: 12 | trap: Unreachable\n",
+            "The trace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_reentrant_step() {
+        let program = assemble(
+            "call_host 0\n\
+             const_8 0x12\n\
+             debug\n",
+        )
+        .unwrap();
+        let mut processor = Processor::new_empty(program, 20);
+        processor
+            .host_functions_mut()
+            .register("step", |processor| processor.step());
+        let tracer = Arc::new(Mutex::new(LineTracer::new(Vec::new())));
+        processor.set_tracer(Some(tracer.clone()));
+
+        let result = processor.run();
+        assert!(result.is_halt(), "The processor must halt");
+        assert_eq!(
+            processor.stack_pointer(),
+            1,
+            "The nested step must be executed"
+        );
+
+        let output = String::from_utf8(tracer.lock().unwrap().output().clone()).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2, "The trace is incorrect:\n{}", output);
+        assert!(
+            lines[0].contains("call_host") && lines[1].contains("debug"),
+            "The trace is incorrect:\n{}",
+            output
+        );
+    }
+}
@@ -0,0 +1,62 @@
+/// An error that aborts the execution of a processor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Trap {
+    /// The position of the instruction that raised the trap. It is set by the processor
+    /// when the trap is raised while executing an instruction, otherwise it is 0.
+    pub program_counter: usize,
+    pub kind: TrapKind,
+}
+
+/// The kind of trap.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TrapKind {
+    /// The `unreachable` instruction has been executed.
+    Unreachable,
+
+    /// The opcode is not assigned to any instruction. It contains the index of the opcode,
+    /// which is 256 or greater for the extended set.
+    InvalidOpcode(usize),
+
+    /// The access to `size` bytes at `address` exceeds the memory.
+    MemoryOutOfBounds { address: usize, size: usize },
+
+    /// The memory cannot grow beyond its maximum number of pages.
+    MemoryLimitExceeded,
+
+    /// The read of `size` bytes at `address` exceeds the program.
+    ProgramOutOfBounds { address: usize, size: usize },
+
+    /// The read of `size` bytes at `address` is outside the data section of the program.
+    DataOutOfBounds { address: usize, size: usize },
+
+    /// The code position is outside the code section of the program.
+    CodeOutOfBounds { address: usize },
+
+    /// The stack is full.
+    StackOverflow,
+
+    /// The stack does not contain enough bytes.
+    StackUnderflow,
+
+    /// The access to the local of `size` bytes at `offset` from the frame pointer is outside
+    /// the used part of the stack.
+    LocalOutOfBounds { offset: i32, size: usize },
+
+    /// The maximum call depth has been reached.
+    CallStackOverflow,
+
+    /// A return is executed outside of any subroutine.
+    CallStackUnderflow,
+
+    /// An integer division or remainder by zero.
+    DivisionByZero,
+
+    /// The result of a conversion does not fit in the target integer.
+    IntegerOverflow,
+
+    /// A NaN is converted to an integer.
+    InvalidConversionToInteger,
+
+    /// No host function is registered at the index.
+    UnknownHostFunction(u32),
+}
@@ -0,0 +1,304 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::TryInto;
+
+use crate::sasm::instructions::Instruction;
+use crate::sasm::Program;
+
+/// An error found by the verifier in the code of a program.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VerifierError {
+    /// The position in the program of the faulty instruction.
+    pub offset: usize,
+    pub kind: VerifierErrorKind,
+}
+
+/// The kind of verifier error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum VerifierErrorKind {
+    /// The opcode is not assigned to any instruction. It contains the index of the opcode,
+    /// which is 256 or greater for the extended set.
+    UnassignedOpcode(usize),
+
+    /// The prefix of an extended opcode is the last byte of the code.
+    TruncatedOpcode,
+
+    /// The immediate values of the instruction, including the entries of branch tables,
+    /// exceed the end of the code.
+    TruncatedImmediate(Instruction),
+
+    /// The instruction pops more bytes than the stack contains at that point.
+    StackUnderflow(Instruction),
+
+    /// The relative branch or an entry of the branch table jumps to a position that is not
+    /// the start of an instruction.
+    InvalidBranchTarget(Instruction),
+}
+
+/// Statically checks the code section of a program before it is executed:
+/// - Every opcode must be assigned to an instruction.
+/// - The immediate values of every instruction must be inside the code.
+/// - Relative branches and the entries of branch tables must jump to the start of
+///   an instruction.
+/// - The stack must not underflow along the straight-line code that starts at the beginning
+///   of the code. Subroutines are expected to return with the stack depth they were called
+///   with, so the code that follows a call is checked as well. The stack effect of host
+///   functions is unknown, so the code that follows them is not checked.
+/// - The code that follows an unconditional jump is only reachable through a branch. Its
+///   stack is checked again from the first target of a relative branch or branch table
+///   whose depth is known, and left unchecked until then because absolute branches can
+///   jump anywhere.
+pub fn verify(program: &Program) -> Result<(), VerifierError> {
+    let code = program.program();
+    let code_end = program.code_pointer_end();
+    let mut position = program.code_pointer();
+    let mut stack_depth = Some(0);
+    let mut instruction_positions = HashSet::new();
+    let mut branches = Vec::new();
+    // The stack depth at the targets of the branches found with a known depth.
+    let mut target_depths = HashMap::new();
+
+    while position < code_end {
+        if stack_depth.is_none() {
+            stack_depth = target_depths.get(&position).copied();
+        }
+
+        let index = match Instruction::read_index(&code[position..code_end]) {
+            Some(v) => v,
+            None => {
+                return Err(VerifierError {
+                    offset: position,
+                    kind: VerifierErrorKind::TruncatedOpcode,
+                })
+            }
+        };
+        let instruction = match Instruction::from_index(index) {
+            Some(v) => v,
+            None => {
+                return Err(VerifierError {
+                    offset: position,
+                    kind: VerifierErrorKind::UnassignedOpcode(index),
+                })
+            }
+        };
+
+        let next_position = match instruction.read_size(&code[position..code_end]) {
+            Some(v) => position + v,
+            None => {
+                return Err(VerifierError {
+                    offset: position,
+                    kind: VerifierErrorKind::TruncatedImmediate(instruction),
+                })
+            }
+        };
+
+        instruction_positions.insert(position);
+        let first_branch = branches.len();
+        if instruction.is_relative_branch() || instruction == Instruction::BranchTable {
+            // The offsets are placed after the opcode, or after the count for tables.
+            let mut offset_position = position + instruction.opcode_size();
+            if instruction == Instruction::BranchTable {
+                offset_position += std::mem::size_of::<u32>();
+            }
+
+            for bytes in code[offset_position..next_position].chunks(std::mem::size_of::<i32>()) {
+                let offset = i32::from_le_bytes(bytes.try_into().unwrap());
+                branches.push((position, instruction, next_position as i64 + offset as i64));
+            }
+        }
+
+        if let Some(depth) = stack_depth {
+            let (pops, pushes) = instruction.stack_effect();
+            if pops > depth {
+                return Err(VerifierError {
+                    offset: position,
+                    kind: VerifierErrorKind::StackUnderflow(instruction),
+                });
+            }
+
+            let depth = depth - pops + pushes;
+            for (_, _, target) in &branches[first_branch..] {
+                target_depths.entry(*target as usize).or_insert(depth);
+            }
+
+            stack_depth = Some(depth);
+        }
+
+        if matches!(
+            instruction,
+            Instruction::Unreachable
+                | Instruction::Branch
+                | Instruction::BranchRel
+                | Instruction::BranchTable
+                | Instruction::CallHost
+                | Instruction::Return
+        ) {
+            stack_depth = None;
+        }
+
+        position = next_position;
+    }
+
+    for (position, instruction, target) in branches {
+        if target < 0 || !instruction_positions.contains(&(target as usize)) {
+            return Err(VerifierError {
+                offset: position,
+                kind: VerifierErrorKind::InvalidBranchTarget(instruction),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::sasm::asm::assemble;
+    use crate::sasm::instructions::EXTENDED_OPCODE;
+
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let program = Program::from_sections(
+            &[0xff, 0xff],
+            &[
+                Instruction::Const32 as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Const8 as u8,
+                0x01,
+                Instruction::BranchIf8 as u8,
+                Instruction::Const32 as u8,
+                0x02,
+                0x00,
+                0x00,
+                0x00,
+                Instruction::Branch as u8,
+                Instruction::Drop64 as u8,
+            ],
+        );
+
+        verify(&program).expect("The verification must succeed");
+    }
+
+    #[test]
+    fn test_verify_errors() {
+        // Case 1: unassigned opcode.
+        let program = Program::from_sections(&[0xff], &[Instruction::Nop as u8, 0xfe]);
+        let error = verify(&program).expect_err("[1] The verification must fail");
+        assert_eq!(error.offset, 2, "[1] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::UnassignedOpcode(0xfe),
+            "[1] The kind is incorrect"
+        );
+
+        // Case 2: truncated immediate.
+        let program = Program::from_sections(&[], &[Instruction::Const32 as u8, 0x01, 0x02]);
+        let error = verify(&program).expect_err("[2] The verification must fail");
+        assert_eq!(error.offset, 0, "[2] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::TruncatedImmediate(Instruction::Const32),
+            "[2] The kind is incorrect"
+        );
+
+        // Case 3: stack underflow.
+        let program = Program::from_sections(
+            &[],
+            &[
+                Instruction::Const16 as u8,
+                0x01,
+                0x02,
+                Instruction::Drop8 as u8,
+                Instruction::Drop16 as u8,
+            ],
+        );
+        let error = verify(&program).expect_err("[3] The verification must fail");
+        assert_eq!(error.offset, 4, "[3] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::StackUnderflow(Instruction::Drop16),
+            "[3] The kind is incorrect"
+        );
+
+        // Case 4: unassigned extended opcode.
+        let program = Program::from_sections(&[], &[EXTENDED_OPCODE, 0xf0]);
+        let error = verify(&program).expect_err("[4] The verification must fail");
+        assert_eq!(error.offset, 0, "[4] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::UnassignedOpcode(0x1f0),
+            "[4] The kind is incorrect"
+        );
+
+        // Case 5: truncated opcode.
+        let program = Program::from_sections(&[], &[Instruction::Nop as u8, EXTENDED_OPCODE]);
+        let error = verify(&program).expect_err("[5] The verification must fail");
+        assert_eq!(error.offset, 1, "[5] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::TruncatedOpcode,
+            "[5] The kind is incorrect"
+        );
+
+        // Case 6: branch into the middle of an instruction.
+        let program = Program::from_sections(
+            &[],
+            &[
+                Instruction::Nop as u8,
+                EXTENDED_OPCODE,
+                (Instruction::BranchRel as usize - 256) as u8,
+                0xfd,
+                0xff,
+                0xff,
+                0xff,
+            ],
+        );
+        let error = verify(&program).expect_err("[6] The verification must fail");
+        assert_eq!(error.offset, 1, "[6] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::InvalidBranchTarget(Instruction::BranchRel),
+            "[6] The kind is incorrect"
+        );
+
+        // Case 7: stack underflow after a call.
+        let program = assemble(
+            "const_8 0x01\n\
+             call_rel sub\n\
+             drop_16\n\
+             sub: return\n",
+        )
+        .unwrap();
+        let error = verify(&program).expect_err("[7] The verification must fail");
+        assert_eq!(error.offset, 8, "[7] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::StackUnderflow(Instruction::Drop16),
+            "[7] The kind is incorrect"
+        );
+
+        // Case 8: stack underflow at the target of a branch after an unconditional jump.
+        let program = assemble(
+            "const_8 0x01\n\
+             branch_rel target\n\
+             drop_64\n\
+             target: drop_16\n",
+        )
+        .unwrap();
+        let error = verify(&program).expect_err("[8] The verification must fail");
+        assert_eq!(error.offset, 9, "[8] The offset is incorrect");
+        assert_eq!(
+            error.kind,
+            VerifierErrorKind::StackUnderflow(Instruction::Drop16),
+            "[8] The kind is incorrect"
+        );
+    }
+}
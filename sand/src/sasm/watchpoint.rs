@@ -0,0 +1,106 @@
+/// A range of memory whose accesses stop the execution of a debugger.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Watchpoint {
+    /// The position of the first watched byte.
+    pub address: usize,
+
+    /// The number of watched bytes.
+    pub size: usize,
+
+    pub kind: WatchpointKind,
+}
+
+/// The kind of accesses a watchpoint stops at.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum WatchpointKind {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+/// An access to the memory that matched a watchpoint.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct WatchpointHit {
+    pub watchpoint: Watchpoint,
+
+    /// The position of the first accessed byte.
+    pub address: usize,
+
+    /// The number of accessed bytes.
+    pub size: usize,
+
+    /// Whether the access was a write instead of a read.
+    pub is_write: bool,
+}
+
+impl Watchpoint {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(address: usize, size: usize, kind: WatchpointKind) -> Watchpoint {
+        Watchpoint {
+            address,
+            size,
+            kind,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether the access of `size` bytes at `address` matches this watchpoint.
+    /// Ranges that exceed the address space end at its last byte.
+    pub fn matches(&self, address: usize, size: usize, is_write: bool) -> bool {
+        let kind_matches = match self.kind {
+            WatchpointKind::Read => !is_write,
+            WatchpointKind::Write => is_write,
+            WatchpointKind::ReadWrite => true,
+        };
+
+        kind_matches
+            && address < self.address.saturating_add(self.size)
+            && self.address < address.saturating_add(size)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let watchpoint = Watchpoint::new(4, 4, WatchpointKind::Write);
+
+        assert!(watchpoint.matches(4, 1, true), "[1] The access must match");
+        assert!(watchpoint.matches(0, 8, true), "[2] The access must match");
+        assert!(watchpoint.matches(7, 4, true), "[3] The access must match");
+        assert!(
+            !watchpoint.matches(0, 4, true),
+            "[4] The access must not match"
+        );
+        assert!(
+            !watchpoint.matches(8, 1, true),
+            "[5] The access must not match"
+        );
+        assert!(
+            !watchpoint.matches(4, 4, false),
+            "[6] The access must not match"
+        );
+
+        let watchpoint = Watchpoint::new(usize::MAX - 1, usize::MAX, WatchpointKind::ReadWrite);
+        assert!(
+            watchpoint.matches(usize::MAX - 1, 1, false),
+            "[7] The access must match"
+        );
+        assert!(
+            watchpoint.matches(usize::MAX - 2, usize::MAX, true),
+            "[8] The access must match"
+        );
+        assert!(
+            !watchpoint.matches(0, usize::MAX - 1, true),
+            "[9] The access must not match"
+        );
+    }
+}
@@ -0,0 +1,443 @@
+use std::io::{BufRead, Write};
+
+use sand::sasm::disasm::{disassemble_instruction, hexdump};
+use sand::sasm::{Debugger, StopReason, Watchpoint, WatchpointKind};
+
+/// The number of bytes of the stack printed by default.
+const DEFAULT_STACK_BYTES: usize = 32;
+
+/// The number of instructions printed by default before and after the program counter.
+const DEFAULT_DISASSEMBLE_CONTEXT: usize = 4;
+
+const HELP: &str = "\
+Commands:
+  step [count]                   Executes `count` instructions, 1 by default.
+  next                           Executes an instruction stepping over calls.
+  continue                       Continues until a breakpoint, watchpoint or stop.
+  break <position>               Adds a breakpoint at a code position.
+  delete <position>              Removes the breakpoint at a code position.
+  breakpoints                    Lists the breakpoints.
+  watch <address> <size> [kind]  Adds a watchpoint of kind read, write or readwrite.
+  registers                      Prints the registers of the processor.
+  stack [size]                   Prints the top `size` bytes of the stack.
+  memory <address> <size>        Prints a hexdump of a memory range.
+  disasm [count]                 Disassembles `count` instructions around the pc.
+  help                           Prints this help.
+  quit                           Exits the debugger.
+Numbers can be written in decimal or in hexadecimal with the `0x` prefix.";
+
+/// An error of a console command.
+enum CommandError {
+    /// The command cannot be executed. The message is shown to the user.
+    Message(String),
+
+    /// The output cannot be written.
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for CommandError {
+    fn from(error: std::io::Error) -> Self {
+        CommandError::Io(error)
+    }
+}
+
+/// An interactive console that reads debugger commands line by line from `input` and
+/// writes their results to `output`, so it can be driven by a terminal or a script.
+pub struct Console<R, W> {
+    debugger: Debugger,
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Console<R, W> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(debugger: Debugger, input: R, output: W) -> Console<R, W> {
+        Console {
+            debugger,
+            input,
+            output,
+        }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Executes commands until the input ends or the `quit` command is read.
+    pub fn run(&mut self) -> std::io::Result<()> {
+        let mut line = String::new();
+        loop {
+            write!(self.output, "(sand) ")?;
+            self.output.flush()?;
+
+            line.clear();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                return Ok(());
+            }
+
+            if !self.execute(line.trim())? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Executes a command and returns whether the console must continue.
+    fn execute(&mut self, line: &str) -> std::io::Result<bool> {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(v) => v,
+            None => return Ok(true),
+        };
+        let arguments: Vec<_> = words.collect();
+
+        let result = match command {
+            "step" | "s" => self.step(&arguments),
+            "next" | "n" => {
+                let reason = self.debugger.step_over();
+                self.print_stop_reason(reason)
+            }
+            "continue" | "c" => {
+                let reason = self.debugger.resume();
+                self.print_stop_reason(reason)
+            }
+            "break" | "b" => self.add_breakpoint(&arguments),
+            "delete" | "d" => self.remove_breakpoint(&arguments),
+            "breakpoints" => self.print_breakpoints(),
+            "watch" | "w" => self.add_watchpoint(&arguments),
+            "registers" | "r" => self.print_registers(),
+            "stack" => self.print_stack(&arguments),
+            "memory" | "x" => self.print_memory(&arguments),
+            "disasm" | "l" => self.print_disassembly(&arguments),
+            "help" | "h" => writeln!(self.output, "{}", HELP).map_err(CommandError::from),
+            "quit" | "q" => return Ok(false),
+            _ => Err(CommandError::Message(format!(
+                "Unknown command '{}'. Type 'help' to list the commands",
+                command
+            ))),
+        };
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(CommandError::Message(message)) => {
+                writeln!(self.output, "Error: {}", message)?;
+                Ok(true)
+            }
+            Err(CommandError::Io(error)) => Err(error),
+        }
+    }
+
+    fn step(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let count = parse_optional_number(arguments.first(), 1)?;
+
+        let mut reason = StopReason::Step;
+        for _ in 0..count {
+            reason = self.debugger.step();
+            if reason != StopReason::Step {
+                break;
+            }
+        }
+
+        self.print_stop_reason(reason)
+    }
+
+    fn add_breakpoint(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let position = parse_number(arguments.first())?;
+        self.debugger.add_breakpoint(position);
+
+        writeln!(self.output, "Breakpoint added at {:#010x}", position)?;
+
+        Ok(())
+    }
+
+    fn remove_breakpoint(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let position = parse_number(arguments.first())?;
+        if !self.debugger.remove_breakpoint(position) {
+            return Err(CommandError::Message(format!(
+                "There is no breakpoint at {:#010x}",
+                position
+            )));
+        }
+
+        writeln!(self.output, "Breakpoint removed at {:#010x}", position)?;
+
+        Ok(())
+    }
+
+    fn print_breakpoints(&mut self) -> Result<(), CommandError> {
+        for position in self.debugger.breakpoints() {
+            writeln!(self.output, "{:#010x}", position)?;
+        }
+
+        Ok(())
+    }
+
+    fn add_watchpoint(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let address = parse_number(arguments.first())?;
+        let size = parse_number(arguments.get(1))?;
+        let kind = match arguments.get(2).copied() {
+            Some("read") => WatchpointKind::Read,
+            Some("write") => WatchpointKind::Write,
+            Some("readwrite") | None => WatchpointKind::ReadWrite,
+            Some(v) => {
+                return Err(CommandError::Message(format!(
+                    "Unknown watchpoint kind '{}'",
+                    v
+                )))
+            }
+        };
+        self.debugger
+            .add_watchpoint(Watchpoint::new(address, size, kind));
+
+        writeln!(
+            self.output,
+            "Watchpoint added at {:#010x} with {} bytes",
+            address, size
+        )?;
+
+        Ok(())
+    }
+
+    fn print_registers(&mut self) -> Result<(), CommandError> {
+        let processor = self.debugger.processor();
+        writeln!(
+            self.output,
+            "program_counter: {:#010x}\n\
+             stack_pointer:   {:#010x}\n\
+             frame_pointer:   {:#010x}\n\
+             overflow_flag:   {}\n\
+             call_depth:      {}",
+            processor.program_counter(),
+            processor.stack_pointer(),
+            processor.frame_pointer(),
+            processor.overflow_flag(),
+            processor.call_depth()
+        )?;
+
+        Ok(())
+    }
+
+    fn print_stack(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let size = parse_optional_number(arguments.first(), DEFAULT_STACK_BYTES)?;
+        let stack_pointer = self.debugger.processor().stack_pointer();
+        if stack_pointer == 0 {
+            writeln!(self.output, "The stack is empty")?;
+            return Ok(());
+        }
+
+        let address = stack_pointer.saturating_sub(size);
+        self.print_hexdump(address, stack_pointer - address)
+    }
+
+    fn print_memory(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let address = parse_number(arguments.first())?;
+        let size = parse_number(arguments.get(1))?;
+
+        self.print_hexdump(address, size)
+    }
+
+    fn print_hexdump(&mut self, address: usize, size: usize) -> Result<(), CommandError> {
+        // Checked before allocating because the size is typed by the user.
+        let memory = self.debugger.processor().memory();
+        match address.checked_add(size) {
+            Some(end) if end <= memory.size() => {}
+            _ => {
+                return Err(CommandError::Message(format!(
+                    "The range of {} bytes at {:#010x} is outside the memory",
+                    size, address
+                )))
+            }
+        }
+
+        let mut bytes = vec![0; size];
+        memory.inspect_at(address, &mut bytes).unwrap();
+
+        write!(self.output, "{}", hexdump(&bytes, address))?;
+
+        Ok(())
+    }
+
+    /// Prints the instructions around the program counter. The code is disassembled from its
+    /// beginning because the instructions have different sizes.
+    fn print_disassembly(&mut self, arguments: &[&str]) -> Result<(), CommandError> {
+        let context = parse_optional_number(arguments.first(), DEFAULT_DISASSEMBLE_CONTEXT)?;
+        let program = self.debugger.processor().program();
+        let program_counter = self.debugger.processor().program_counter();
+
+        let mut lines = Vec::new();
+        let mut position = program.code_pointer();
+        while let Some((text, size)) = disassemble_instruction(program, position) {
+            lines.push((position, text));
+            position += size;
+        }
+
+        let current = lines
+            .iter()
+            .position(|(position, _)| *position >= program_counter)
+            .unwrap_or(lines.len());
+        let first = current.saturating_sub(context);
+        let last = (current + context + 1).min(lines.len());
+        for (position, text) in &lines[first..last] {
+            let marker = if *position == program_counter {
+                "=>"
+            } else {
+                "  "
+            };
+            writeln!(self.output, "{} {:#010x}  {}", marker, position, text)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_stop_reason(&mut self, reason: StopReason) -> Result<(), CommandError> {
+        match reason {
+            StopReason::Step => {}
+            StopReason::Breakpoint(position) => {
+                writeln!(self.output, "Breakpoint at {:#010x}", position)?;
+            }
+            StopReason::Watchpoint(hit) => {
+                let access = if hit.is_write { "Write" } else { "Read" };
+                writeln!(
+                    self.output,
+                    "{} of {} bytes at {:#010x}",
+                    access, hit.size, hit.address
+                )?;
+            }
+            StopReason::Halt => {
+                writeln!(self.output, "Halted")?;
+            }
+            StopReason::Trap(trap) => {
+                writeln!(
+                    self.output,
+                    "Trap at {:#010x}: {:?}",
+                    trap.program_counter, trap.kind
+                )?;
+                return Ok(());
+            }
+            StopReason::End => {
+                writeln!(self.output, "End of the code")?;
+                return Ok(());
+            }
+            StopReason::OutOfFuel => {
+                writeln!(self.output, "Out of fuel")?;
+            }
+        }
+
+        self.print_current_instruction()
+    }
+
+    fn print_current_instruction(&mut self) -> Result<(), CommandError> {
+        let processor = self.debugger.processor();
+        let program_counter = processor.program_counter();
+        if let Some((text, _)) = disassemble_instruction(processor.program(), program_counter) {
+            writeln!(self.output, "{:#010x}  {}", program_counter, text)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a number written in decimal or in hexadecimal with the `0x` prefix.
+fn parse_number(argument: Option<&&str>) -> Result<usize, CommandError> {
+    let argument = match argument {
+        Some(v) => *v,
+        None => return Err(CommandError::Message("Missing argument".to_string())),
+    };
+
+    let result = match argument.strip_prefix("0x") {
+        Some(v) => usize::from_str_radix(v, 16),
+        None => argument.parse(),
+    };
+
+    result.map_err(|_| CommandError::Message(format!("Invalid number '{}'", argument)))
+}
+
+fn parse_optional_number(argument: Option<&&str>, default: usize) -> Result<usize, CommandError> {
+    match argument {
+        Some(_) => parse_number(argument),
+        None => Ok(default),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use sand::sasm::asm::assemble;
+    use sand::sasm::Processor;
+
+    use super::*;
+
+    fn run_script(source: &str, script: &str) -> String {
+        let program = assemble(source).expect("The program must assemble");
+        let debugger = Debugger::new(Processor::new_empty(program, 20));
+        let mut output = Vec::new();
+
+        let mut console = Console::new(debugger, script.as_bytes(), &mut output);
+        console.run().expect("The console must not fail");
+
+        String::from_utf8(output).expect("The output must be UTF-8")
+    }
+
+    #[test]
+    fn test_console() {
+        let source = "\
+            const_8 0x12\n\
+            const_8 0x34\n\
+            debug\n\
+            add_8\n\
+            unreachable\n";
+
+        // Case 1
+        let result = run_script(source, "step\nregisters\nstack\nquit\n");
+        assert_eq!(
+            result,
+            "(sand) 0x00000002  const_8 0x34\n\
+             (sand) program_counter: 0x00000002\n\
+             stack_pointer:   0x00000001\n\
+             frame_pointer:   0x00000000\n\
+             overflow_flag:   false\n\
+             call_depth:      0\n\
+             (sand) 0x00000000  12                                                |.|\n\
+             (sand) ",
+            "[1] The output is incorrect"
+        );
+
+        // Case 2
+        let result = run_script(source, "break 5\ncontinue\ncontinue\ncontinue\n");
+        assert_eq!(
+            result,
+            "(sand) Breakpoint added at 0x00000005\n\
+             (sand) Halted\n\
+             0x00000005  add_8\n\
+             (sand) Trap at 0x00000006: Unreachable\n\
+             (sand) End of the code\n\
+             (sand) \n",
+            "[2] The output is incorrect"
+        );
+
+        // Case 3
+        let result = run_script(source, "disasm 1\nfoo\nstep\nmemory 0 1\n");
+        assert_eq!(
+            result,
+            "(sand) => 0x00000000  const_8 0x12\n   0x00000002  const_8 0x34\n\
+             (sand) Error: Unknown command 'foo'. Type 'help' to list the commands\n\
+             (sand) 0x00000002  const_8 0x34\n\
+             (sand) 0x00000000  12                                                |.|\n\
+             (sand) \n",
+            "[3] The output is incorrect"
+        );
+
+        // Case 4
+        let result = run_script(source, "memory 0 0xffffffffffffffff\nmemory 0xffffffff 1\n");
+        assert_eq!(
+            result,
+            "(sand) Error: The range of 18446744073709551615 bytes at 0x00000000 is outside \
+             the memory\n\
+             (sand) Error: The range of 1 bytes at 0xffffffff is outside the memory\n\
+             (sand) \n",
+            "[4] The output is incorrect"
+        );
+    }
+}
@@ -1,13 +1,18 @@
 #[macro_use]
 extern crate log;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use clap::{App, Arg, ArgMatches};
+use clap::{App, AppSettings, Arg, ArgMatches};
 
+use sand::sasm::asm::assemble;
+use sand::sasm::{Debugger, Processor, Program, MEMORY_DEFAULT_STACK_SIZE};
+
+use crate::debugger::Console;
 use crate::parser::parse_file;
-use crate::utils::fs::read_file;
+use crate::utils::fs::{read_binary_file, read_file};
 
+mod debugger;
 mod parser;
 mod utils;
 
@@ -16,6 +21,11 @@ fn main() {
 
     let matches = define_cli();
 
+    if let Some(matches) = matches.subcommand_matches("debug") {
+        debug_program(matches);
+        return;
+    }
+
     let file_path = matches.value_of("file").unwrap();
     let file_path_buf = PathBuf::from(file_path);
 
@@ -44,15 +54,55 @@ fn define_cli() -> ArgMatches {
     App::new("sandc")
         .about("The Sand language compiler")
         .version(clap::crate_version!())
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(
             Arg::new("file")
                 .about("the input file")
                 .index(1)
                 .required(true),
         )
+        .subcommand(
+            App::new("debug")
+                .about("Debugs a program interactively over the standard input and output")
+                .arg(
+                    Arg::new("file")
+                        .about("the program file: a .sasm listing or a binary program")
+                        .index(1)
+                        .required(true),
+                ),
+        )
         .get_matches()
 }
 
+fn debug_program(matches: &ArgMatches) {
+    let file_path = matches.value_of("file").unwrap();
+    let program = match load_program(Path::new(file_path)) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Cannot load the program: {}", e);
+            return;
+        }
+    };
+
+    let debugger = Debugger::new(Processor::new_empty(program, MEMORY_DEFAULT_STACK_SIZE));
+    let stdin = std::io::stdin();
+    let mut console = Console::new(debugger, stdin.lock(), std::io::stdout());
+    if let Err(e) = console.run() {
+        error!("Debugger error: {}", e);
+    }
+}
+
+/// Loads a program from a .sasm listing, which is assembled, or from a binary program.
+fn load_program(path: &Path) -> Result<Program, String> {
+    if matches!(path.extension(), Some(v) if v == "sasm") {
+        let content = read_file(path).map_err(|e| e.to_string())?;
+        assemble(&content).map_err(|e| format!("Assembler error at line {}: {:?}", e.line, e.kind))
+    } else {
+        let content = read_binary_file(path).map_err(|e| e.to_string())?;
+        Program::from_bytes(&content).map_err(|e| format!("Invalid program: {:?}", e))
+    }
+}
+
 fn configure_logger() {
     if std::env::var("RUST_LOG").is_err() {
         std::env::set_var("RUST_LOG", "info")
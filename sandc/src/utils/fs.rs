@@ -9,3 +9,11 @@ pub fn read_file(path: &Path) -> Result<String, std::io::Error> {
 
     Ok(content)
 }
+
+pub fn read_binary_file(path: &Path) -> Result<Vec<u8>, std::io::Error> {
+    let mut file = File::open(path)?;
+    let mut content = Vec::new();
+    let _ = file.read_to_end(&mut content)?;
+
+    Ok(content)
+}